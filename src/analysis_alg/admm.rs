@@ -0,0 +1,81 @@
+//! # Analysis ADMM
+//!
+//! ADMM for analysis l1 regularized least squares
+//! (minimize (1/2)||y - Ax||^(2) + λ||Ωx||_(1)).
+//! Small λ approximates minimize ||Ωx||_(1) s.t. y = Ax.
+use super::{check_analysis_sys, AnalysisAlg};
+use crate::prelude::*;
+
+pub struct AnalysisAdmm {
+    lambda: f64,
+    rho: f64,
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl AnalysisAdmm {
+    #[allow(dead_code)]
+    pub fn new(lambda: f64, rho: f64, iter_num: usize, threshold: f64) -> Result<Self> {
+        if rho <= 0. {
+            return Err(anyhow!(format!(
+                "rho is {}, it is needed to be positive",
+                rho
+            )));
+        }
+        Ok(Self {
+            lambda,
+            rho,
+            iter_num,
+            threshold,
+        })
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, lambda: f64, rho: f64, iter_num: usize, threshold: f64) -> Result<()> {
+        if rho <= 0. {
+            return Err(anyhow!(format!(
+                "rho is {}, it is needed to be positive",
+                rho
+            )));
+        }
+        self.lambda = lambda;
+        self.rho = rho;
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+        Ok(())
+    }
+}
+
+impl AnalysisAlg for AnalysisAdmm {
+    fn solve(
+        &self,
+        mat: &Array2<f64>,
+        omega: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<Array1<f64>> {
+        check_analysis_sys(mat, omega, y)?;
+
+        //initialization
+        //x update solves (A^T A + ρ Ω^T Ω) x = A^T y + ρ Ω^T (z - u)
+        let system_inv = (mat.t().dot(mat) + self.rho * omega.t().dot(omega))
+            .inv()
+            .map_err(|_| anyhow!("A^T A + ρ Ω^T Ω is singular"))?;
+        let mat_t_y = mat.t().dot(y);
+        let mut x: Array1<f64> = Array::zeros(mat.ncols());
+        let mut z: Array1<f64> = Array::zeros(omega.nrows());
+        let mut u: Array1<f64> = Array::zeros(omega.nrows());
+
+        for _ in 0..self.iter_num {
+            let prev_x = x.clone();
+            x = system_inv.dot(&(&mat_t_y + &(self.rho * omega.t().dot(&(&z - &u)))));
+            let omega_x = omega.dot(&x);
+            z = st_array1(self.lambda / self.rho, &(&omega_x + &u));
+            u = u + &omega_x - &z;
+
+            if (&x - &prev_x).norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        Ok(x)
+    }
+}
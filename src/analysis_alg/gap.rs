@@ -0,0 +1,86 @@
+//! # GAP
+//!
+//! Greedy analysis pursuit(Nam et al.).
+//! Starting from the cosupport Λ = all rows of Ω, it solves
+//! minimize ||Ω_Λ x||^(2) s.t. y = Ax and removes the row with the largest |(Ωx)_i| from Λ.
+use super::{check_analysis_sys, AnalysisAlg};
+use crate::prelude::*;
+
+pub struct Gap {
+    threshold: f64,
+    iter_num: usize,
+}
+
+impl Gap {
+    #[allow(dead_code)]
+    pub fn new(threshold: f64, iter_num: usize) -> Self {
+        Self {
+            threshold,
+            iter_num,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, threshold: f64, iter_num: usize) {
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+    }
+}
+
+///minimize ||Ω_Λ x||^(2) s.t. y = Ax by the KKT system [Ω_Λ^T Ω_Λ, A^T; A, 0][x; ν] = [0; y]
+fn min_cosupport_energy(
+    mat: &Array2<f64>,
+    omega: &Array2<f64>,
+    cosupport: &[usize],
+    y: &Array1<f64>,
+) -> Result<Array1<f64>> {
+    let (m, n) = (mat.nrows(), mat.ncols());
+    let omega_sub = omega.select(Axis(0), cosupport);
+    let mut kkt: Array2<f64> = Array::zeros((n + m, n + m));
+    kkt.slice_mut(s![..n, ..n])
+        .assign(&omega_sub.t().dot(&omega_sub));
+    kkt.slice_mut(s![..n, n..]).assign(&mat.t());
+    kkt.slice_mut(s![n.., ..n]).assign(mat);
+    let mut rhs: Array1<f64> = Array::zeros(n + m);
+    rhs.slice_mut(s![n..]).assign(y);
+    Ok(pseudo_inverse(&kkt)?.dot(&rhs).slice(s![..n]).to_owned())
+}
+
+impl AnalysisAlg for Gap {
+    fn solve(
+        &self,
+        mat: &Array2<f64>,
+        omega: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<Array1<f64>> {
+        check_analysis_sys(mat, omega, y)?;
+
+        //initialization
+        let mut cosupport: Vec<usize> = (0..omega.nrows()).collect();
+        let mut x = min_cosupport_energy(mat, omega, &cosupport, y)?;
+
+        for _ in 0..self.iter_num {
+            //the row of the cosupport with the largest analysis coefficient
+            let omega_x = omega.dot(&x);
+            if omega_x.iter().any(|v| v.is_nan()) {
+                return Err(anyhow!("analysis coefficients of x have NaN"));
+            }
+            let (pos, max) = cosupport
+                .iter()
+                .map(|i| omega_x[*i].abs())
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .ok_or(anyhow!("cosupport is empty"))?;
+            if max < self.threshold {
+                break;
+            }
+
+            cosupport.remove(pos);
+            if cosupport.is_empty() {
+                break;
+            }
+            x = min_cosupport_energy(mat, omega, &cosupport, y)?;
+        }
+
+        Ok(x)
+    }
+}
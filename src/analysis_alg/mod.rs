@@ -0,0 +1,35 @@
+//! # Analysis Alg
+//!
+//! 'analysis_alg' is a collection of algorithms for the analysis(cosparse) model.
+//! They calculate x such that Ωx is sparse and y = Ax for a given analysis operator Ω
+//! (e.g. mk_diff_mat and mk_redundant_dct_mat).
+
+mod admm;
+mod gap;
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+pub use admm::AnalysisAdmm;
+pub use gap::Gap;
+
+pub trait AnalysisAlg {
+    fn solve(&self, mat: &Array2<f64>, omega: &Array2<f64>, y: &Array1<f64>)
+        -> Result<Array1<f64>>;
+}
+
+fn check_analysis_sys(mat: &Array2<f64>, omega: &Array2<f64>, y: &Array1<f64>) -> Result<()> {
+    is_underestimated_sys(mat, y)?;
+    if omega.ncols() != mat.ncols() {
+        return Err(anyhow!(format!(
+            "omega has {} columns, it is needed to be {}",
+            omega.ncols(),
+            mat.ncols()
+        )));
+    }
+    if omega.nrows() == 0 {
+        return Err(anyhow!("omega is needed to have at least one row"));
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+#[test]
+fn analysis_1sample_test() {
+    let mut rng = test_rng();
+    //piecewise constant signal, sparse under the finite difference operator
+    let input_data: Array1<f64> = Array::from_shape_fn(50, |i| match i {
+        0..=14 => 1.,
+        15..=29 => -0.5,
+        _ => 2.,
+    });
+    let matrix: Array2<f64> = rand_gaussian_mat(&mut rng, (30, 50), 1.);
+    let output_data = matrix.dot(&input_data);
+    let omega = mk_diff_mat(50);
+
+    let admm = AnalysisAdmm::new(1e-4, 1., 10000, 1e-12).unwrap();
+    let admm_result = admm.solve(&matrix, &omega, &output_data).unwrap();
+    let gap = Gap::new(1e-6, 49);
+    let gap_result = gap.solve(&matrix, &omega, &output_data).unwrap();
+    //synthesis model for comparison
+    let lasso = SparseAlgLasso::new(1e-4, Box::new(LassoFista::new(10000, 1e-12)), false);
+    let lasso_result = lasso.solve(&matrix, &output_data).unwrap();
+
+    let admm_err = l2_relative_err(&input_data, &admm_result).unwrap();
+    let gap_err = l2_relative_err(&input_data, &gap_result).unwrap();
+    let lasso_err = l2_relative_err(&input_data, &lasso_result).unwrap();
+    println!(
+        "analysis admm: {}, gap: {}, synthesis lasso: {}",
+        admm_err, gap_err, lasso_err
+    );
+    assert!(admm_err < 1e-2);
+    assert!(gap_err < 1e-6);
+    assert!(admm_err < lasso_err);
+}
+
+#[test]
+fn redundant_dct_test() {
+    let omega = mk_redundant_dct_mat(64, 32).unwrap();
+    assert_eq!(omega.shape(), &[64, 32]);
+    for row in omega.rows() {
+        assert!((row.norm_l2() - 1.).abs() < 1e-12);
+    }
+    assert!(mk_redundant_dct_mat(16, 32).is_err());
+}
+
+#[test]
+fn invalid_analysis_sys_test() {
+    let mut rng = test_rng();
+    let matrix: Array2<f64> = rand_gaussian_mat(&mut rng, (5, 10), 1.);
+    let mut output_data: Array1<f64> = Array::ones(5);
+    let gap = Gap::new(1e-6, 9);
+    let admm = AnalysisAdmm::new(1e-4, 1., 100, 1e-12).unwrap();
+
+    //omega without rows
+    let empty: Array2<f64> = Array::zeros((0, 10));
+    assert!(gap.solve(&matrix, &empty, &output_data).is_err());
+    assert!(admm.solve(&matrix, &empty, &output_data).is_err());
+
+    //NaN analysis coefficients
+    output_data[0] = f64::NAN;
+    assert!(gap.solve(&matrix, &mk_diff_mat(10), &output_data).is_err());
+}
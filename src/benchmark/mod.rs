@@ -0,0 +1,271 @@
+//! # Benchmark
+//!
+//! 'benchmark' compares registered algorithms on randomly generated problems,
+//! and records recovery error, support distance, runtime and the number of iterations.
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+use std::time::Instant;
+
+///Algorithm registered in Benchmark. LassoAlg is run with its lambda.
+pub enum BenchAlg {
+    Sparse(Box<dyn SparseAlg>),
+    Lasso(Box<dyn LassoAlg>, f64),
+}
+
+impl BenchAlg {
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        match self {
+            Self::Sparse(alg) => alg.solve_with_iter(mat, y),
+            Self::Lasso(alg, lambda) => alg.solve_with_iter(mat, y, *lambda),
+        }
+    }
+}
+
+///Metric to plot.
+#[derive(Clone, Copy, Debug)]
+pub enum BenchMetric {
+    L2RelativeErr,
+    SupportDistance,
+    RuntimeSec,
+}
+
+pub struct Benchmark {
+    algs: Vec<(String, BenchAlg)>,
+    matrix_shape: (usize, usize),
+    supp_sizes: Vec<usize>,
+    trial_num: usize,
+    pulse_value_range: (f64, f64),
+    snr_db: Option<f64>,
+    supp_err_range: f64,
+}
+
+impl Benchmark {
+    ///Problems are A(matrix_shape, normalized gaussian columns), x(|supp_sizes| pulses) and y = Ax.
+    pub fn new(matrix_shape: (usize, usize), supp_sizes: Vec<usize>, trial_num: usize) -> Self {
+        Self {
+            algs: Vec::new(),
+            matrix_shape,
+            supp_sizes,
+            trial_num,
+            pulse_value_range: (1.0, 2.0),
+            snr_db: None,
+            supp_err_range: 1e-2,
+        }
+    }
+    ///Range of pulses' absolute values.
+    pub fn set_pulse_value_range(&mut self, min_abs: f64, max_abs: f64) {
+        self.pulse_value_range = (min_abs, max_abs);
+    }
+    ///If snr_db is Some, white gaussian noise is added to y.
+    pub fn set_snr_db(&mut self, snr_db: Option<f64>) {
+        self.snr_db = snr_db;
+    }
+    pub fn set_supp_err_range(&mut self, supp_err_range: f64) {
+        self.supp_err_range = supp_err_range;
+    }
+    pub fn add_sparse_alg(&mut self, name: &str, alg: Box<dyn SparseAlg>) {
+        self.algs.push((name.to_string(), BenchAlg::Sparse(alg)));
+    }
+    pub fn add_lasso_alg(&mut self, name: &str, alg: Box<dyn LassoAlg>, lambda: f64) {
+        self.algs
+            .push((name.to_string(), BenchAlg::Lasso(alg, lambda)));
+    }
+
+    ///Run all algorithms on the same problems.
+    ///A trial where an algorithm returns Err is recorded as a failed record.
+    pub fn run<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<BenchResult> {
+        let mut records = Vec::new();
+
+        for supp_size in self.supp_sizes.iter() {
+            for trial in 0..self.trial_num {
+                let mat = normalize_columns(&rand_gaussian_mat(rng, self.matrix_shape, 1.))?;
+                let x = rand_pulses_signal(
+                    rng,
+                    self.matrix_shape.1,
+                    *supp_size,
+                    self.pulse_value_range.0,
+                    self.pulse_value_range.1,
+                )?;
+                let y = match self.snr_db {
+                    Some(snr_db) => noisy_measurement(rng, &mat, &x, snr_db)?,
+                    None => mat.dot(&x),
+                };
+
+                for (name, alg) in self.algs.iter() {
+                    let start = Instant::now();
+                    let solved = alg.solve_with_iter(&mat, &y);
+                    let runtime_sec = start.elapsed().as_secs_f64();
+
+                    let mut record = BenchRecord {
+                        alg: name.clone(),
+                        supp_size: *supp_size,
+                        trial,
+                        l2_relative_err: f64::NAN,
+                        support_distance: f64::NAN,
+                        runtime_sec,
+                        iter_num: None,
+                        error: None,
+                    };
+                    match solved {
+                        Ok((estimated_x, iter_num)) => {
+                            record.l2_relative_err = l2_relative_err(&x, &estimated_x)?;
+                            record.support_distance =
+                                support_distance(&x, &estimated_x, self.supp_err_range)?;
+                            record.iter_num = iter_num;
+                        }
+                        Err(err) => record.error = Some(err.to_string()),
+                    }
+                    records.push(record);
+                }
+            }
+        }
+
+        Ok(BenchResult {
+            alg_names: self.algs.iter().map(|(name, _)| name.clone()).collect(),
+            records,
+        })
+    }
+}
+
+///Result of one algorithm on one problem.
+///If the algorithm failed, error is its message and l2_relative_err and support_distance are NaN.
+#[derive(Clone, Debug)]
+pub struct BenchRecord {
+    pub alg: String,
+    pub supp_size: usize,
+    pub trial: usize,
+    pub l2_relative_err: f64,
+    pub support_distance: f64,
+    pub runtime_sec: f64,
+    pub iter_num: Option<usize>,
+    pub error: Option<String>,
+}
+
+impl BenchRecord {
+    fn metric(&self, metric: BenchMetric) -> f64 {
+        match metric {
+            BenchMetric::L2RelativeErr => self.l2_relative_err,
+            BenchMetric::SupportDistance => self.support_distance,
+            BenchMetric::RuntimeSec => self.runtime_sec,
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub alg_names: Vec<String>,
+    pub records: Vec<BenchRecord>,
+}
+
+impl BenchResult {
+    ///Average of metric over trials which didn't fail for each support size.
+    pub fn mean(&self, alg: &str, metric: BenchMetric) -> Vec<(usize, f64)> {
+        let mut means: Vec<(usize, f64, usize)> = Vec::new();
+        for record in self
+            .records
+            .iter()
+            .filter(|r| r.alg == alg && r.error.is_none())
+        {
+            match means.iter_mut().find(|(s, _, _)| *s == record.supp_size) {
+                Some((_, sum, num)) => {
+                    *sum += record.metric(metric);
+                    *num += 1;
+                }
+                None => means.push((record.supp_size, record.metric(metric), 1)),
+            }
+        }
+        means
+            .into_iter()
+            .map(|(supp_size, sum, num)| (supp_size, sum / num as f64))
+            .collect()
+    }
+
+    ///The number of failed trials of alg.
+    pub fn failure_num(&self, alg: &str) -> usize {
+        self.records
+            .iter()
+            .filter(|r| r.alg == alg && r.error.is_some())
+            .count()
+    }
+
+    ///Write all records.
+    pub fn write_csv(&self, path: &str) -> Result<()> {
+        let mut writer = csv::Writer::from_path(path)?;
+        writer.write_record([
+            "alg",
+            "supp_size",
+            "trial",
+            "l2_relative_err",
+            "support_distance",
+            "runtime_sec",
+            "iter_num",
+            "error",
+        ])?;
+        for record in self.records.iter() {
+            writer.write_record(&[
+                record.alg.clone(),
+                record.supp_size.to_string(),
+                record.trial.to_string(),
+                record.l2_relative_err.to_string(),
+                record.support_distance.to_string(),
+                record.runtime_sec.to_string(),
+                record
+                    .iter_num
+                    .map_or_else(String::new, |iter_num| iter_num.to_string()),
+                record.error.clone().unwrap_or_default(),
+            ])?;
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    ///Plot average of metric for each algorithm(x: support size).
+    pub fn plot(&self, path: &str, caption: &str, metric: BenchMetric) -> Result<()> {
+        let means: Vec<(&String, Vec<(usize, f64)>)> = self
+            .alg_names
+            .iter()
+            .map(|name| (name, self.mean(name, metric)))
+            .collect();
+        let supp_max = self.records.iter().map(|r| r.supp_size).max().unwrap_or(0);
+        let supp_min = self.records.iter().map(|r| r.supp_size).min().unwrap_or(0);
+        let max = means
+            .iter()
+            .flat_map(|(_, mean)| mean.iter().map(|(_, v)| *v))
+            .fold(0., f64::max);
+
+        let root = BitMapBackend::new(path, (640, 480)).into_drawing_area();
+        root.fill(&WHITE)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption(caption, ("sans-serif", 20).into_font())
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(40)
+            .build_cartesian_2d(supp_min..supp_max + 1, 0.0..max * 1.1 + F64_EPS)?;
+        chart.configure_mesh().draw()?;
+
+        for (i, (name, mean)) in means.into_iter().enumerate() {
+            let color = Palette99::pick(i);
+            chart
+                .draw_series(LineSeries::new(mean, color.stroke_width(2)))?
+                .label(name.as_str())
+                .legend(move |(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], Palette99::pick(i))
+                });
+        }
+        chart
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
+        root.present()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+#[test]
+fn benchmark_test() {
+    let mut benchmark = Benchmark::new((30, 50), (1..11).collect(), 20);
+    benchmark.add_sparse_alg("omp", Box::new(Omp::new(1e-2, 100)));
+    benchmark.add_sparse_alg("mp", Box::new(Mp::new(1e-2, 1000)));
+    benchmark.add_lasso_alg("fista", Box::new(LassoFista::new(1000, 1e-8)), 1e-2);
+    benchmark.add_sparse_alg(
+        "dantzig",
+        Box::new(DantzigSelector::new(DantzigDelta::Fixed(1e-2), 1e-9, 100)),
+    );
+
+    let mut rng = test_rng();
+    let result = benchmark.run(&mut rng).expect("failed to run benchmark");
+    assert_eq!(result.records.len(), 4 * 10 * 20);
+    assert!(result
+        .records
+        .iter()
+        .filter(|r| r.alg == "omp")
+        .all(|r| r.iter_num.is_some()));
+
+    result
+        .write_csv("results/benchmark.csv")
+        .expect("failed to write csv");
+    result
+        .plot(
+            "results/benchmark_l2_rerr.png",
+            "benchmark/x: pulse num/y: l2 relative error",
+            BenchMetric::L2RelativeErr,
+        )
+        .expect("failed to plot");
+    result
+        .plot(
+            "results/benchmark_supp_dist.png",
+            "benchmark/x: pulse num/y: support distance",
+            BenchMetric::SupportDistance,
+        )
+        .expect("failed to plot");
+}
+
+struct FailingAlg;
+
+impl SparseAlg for FailingAlg {
+    fn solve(&self, _mat: &Array2<f64>, _y: &Array1<f64>) -> Result<Array1<f64>> {
+        Err(anyhow!("failed"))
+    }
+}
+
+#[test]
+fn failed_trial_test() {
+    let mut benchmark = Benchmark::new((10, 20), vec![1, 2], 3);
+    benchmark.add_sparse_alg("omp", Box::new(Omp::new(1e-2, 100)));
+    benchmark.add_sparse_alg("failing", Box::new(FailingAlg));
+
+    let mut rng = test_rng();
+    let result = benchmark.run(&mut rng).expect("failed to run benchmark");
+    assert_eq!(result.records.len(), 2 * 2 * 3);
+    assert_eq!(result.failure_num("omp"), 0);
+    assert_eq!(result.failure_num("failing"), 2 * 3);
+    assert!(result
+        .records
+        .iter()
+        .filter(|r| r.alg == "failing")
+        .all(|r| r.error.is_some() && r.l2_relative_err.is_nan()));
+    assert_eq!(result.mean("omp", BenchMetric::L2RelativeErr).len(), 2);
+    assert!(result
+        .mean("failing", BenchMetric::L2RelativeErr)
+        .is_empty());
+}
@@ -0,0 +1,136 @@
+//! # Alg
+//!
+//! Algorithms selectable by `--alg`.
+use crate::SolveArgs;
+use anyhow::{anyhow, Result};
+use ndarray::prelude::*;
+use sparse_modeling::{lasso_alg::*, sparse_alg::*};
+
+pub const ALGORITHMS: &[(&str, &str)] = &[
+    ("omp", "orthogonal matching pursuit(threshold, iter-num)"),
+    ("mp", "matching pursuit(threshold, iter-num)"),
+    (
+        "wmp",
+        "weak matching pursuit(threshold, iter-num, proj-ratio)",
+    ),
+    ("threshold", "threshold algorithm(support-size)"),
+    ("ista", "lasso by ISTA(lambda, threshold, iter-num)"),
+    (
+        "ista-ls",
+        "lasso by ISTA with lipshitz search(lambda, threshold, iter-num)",
+    ),
+    ("fista", "lasso by FISTA(lambda, threshold, iter-num)"),
+    (
+        "irls",
+        "lasso by IRLS(lambda, threshold, iter-num, upsilon)",
+    ),
+    (
+        "irls-shrink",
+        "lasso by IRLS shrinkage(lambda, threshold, iter-num)",
+    ),
+    ("ssf", "lasso by SSF(lambda, threshold, iter-num)"),
+    (
+        "lasso-bp",
+        "basis pursuit by FISTA with normalized columns(lambda, threshold, iter-num)",
+    ),
+    ("focuss", "l1 relaxzation by FOCUSS(threshold, iter-num)"),
+    (
+        "dantzig",
+        "dantzig selector with delta = lambda or from noise-std(threshold, iter-num, lambda, noise-std)",
+    ),
+    ("rvm", "relevance vector machine(threshold, iter-num)"),
+    (
+        "fast-sbl",
+        "fast marginal likelihood sparse bayesian learning(threshold, iter-num)",
+    ),
+    (
+        "bcs-laplace",
+        "bayesian compressive sensing with laplace priors(threshold, iter-num)",
+    ),
+];
+
+///Algorithm selected by `--alg`. LassoAlg is run with its lambda.
+pub enum CliAlg {
+    Sparse(Box<dyn SparseAlg>),
+    Lasso(Box<dyn LassoAlg>, f64),
+}
+
+impl CliAlg {
+    pub fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        match self {
+            Self::Sparse(alg) => alg.solve_with_iter(mat, y),
+            Self::Lasso(alg, lambda) => alg.solve_with_iter(mat, y, *lambda),
+        }
+    }
+}
+
+pub fn build_alg(args: &SolveArgs) -> Result<CliAlg> {
+    let alg = match args.alg.as_str() {
+        "omp" => CliAlg::Sparse(Box::new(Omp::new(args.threshold, args.iter_num))),
+        "mp" => CliAlg::Sparse(Box::new(Mp::new(args.threshold, args.iter_num))),
+        "wmp" => CliAlg::Sparse(Box::new(Wmp::new(
+            args.threshold,
+            args.iter_num,
+            args.proj_ratio,
+        )?)),
+        "threshold" => CliAlg::Sparse(Box::new(ThresholdAlg::new(
+            args.support_size
+                .ok_or_else(|| anyhow!("threshold needs --support-size"))?,
+        ))),
+        "ista" => CliAlg::Lasso(
+            Box::new(LassoIsta::new(args.iter_num, args.threshold)),
+            args.lambda,
+        ),
+        "ista-ls" => CliAlg::Lasso(
+            Box::new(LassoIstaLipshitzSearch::new(args.iter_num, args.threshold)),
+            args.lambda,
+        ),
+        "fista" => CliAlg::Lasso(
+            Box::new(LassoFista::new(args.iter_num, args.threshold)),
+            args.lambda,
+        ),
+        "irls" => CliAlg::Lasso(
+            Box::new(LassoIrls::new(args.iter_num, args.threshold, args.upsilon)),
+            args.lambda,
+        ),
+        "irls-shrink" => CliAlg::Lasso(
+            Box::new(LassoIrlsShrink::new(args.iter_num, args.threshold)),
+            args.lambda,
+        ),
+        "ssf" => CliAlg::Lasso(
+            Box::new(LassoSSF::new(args.iter_num, args.threshold)),
+            args.lambda,
+        ),
+        "lasso-bp" => CliAlg::Sparse(Box::new(SparseAlgLasso::new(
+            args.lambda,
+            Box::new(LassoFista::new(args.iter_num, args.threshold)),
+            true,
+        ))),
+        "focuss" => CliAlg::Sparse(Box::new(L1Focuss::new(args.threshold, args.iter_num, true))),
+        "dantzig" => {
+            let delta = match args.noise_std {
+                Some(noise_std) => DantzigDelta::NoiseStd(noise_std),
+                None => DantzigDelta::Fixed(args.lambda),
+            };
+            CliAlg::Sparse(Box::new(DantzigSelector::new(
+                delta,
+                args.threshold,
+                args.iter_num,
+            )))
+        }
+        "rvm" => CliAlg::Sparse(Box::new(Rvm::new(args.threshold, args.iter_num))),
+        "fast-sbl" => CliAlg::Sparse(Box::new(FastSbl::new(args.threshold, args.iter_num))),
+        "bcs-laplace" => CliAlg::Sparse(Box::new(BcsLaplace::new(args.threshold, args.iter_num))),
+        name => {
+            return Err(anyhow!(format!(
+                "unknown algorithm {}. run `sparse_modeling list` to see algorithms",
+                name
+            )))
+        }
+    };
+    Ok(alg)
+}
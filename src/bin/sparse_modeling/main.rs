@@ -0,0 +1,122 @@
+//! # sparse_modeling
+//!
+//! Command-line tool to calculate sparse solutions of y = Ax from files.
+mod alg;
+#[cfg(test)]
+mod tests;
+
+use alg::{build_alg, ALGORITHMS};
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use ndarray_linalg::Norm;
+use sparse_modeling::{file_io::*, math_func::support};
+use std::{fmt::Write, fs, time::Instant};
+
+#[derive(Parser)]
+#[command(version, about = "Calculate sparse solutions of y = Ax")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    ///Solve y = Ax for sparse x. Matrices and vectors are read from .csv, .npy, .mtx or .npz files.
+    ///An array in .npz file is specified as "path.npz:name".
+    Solve(SolveArgs),
+    ///List available algorithms and their parameters.
+    List,
+}
+
+#[derive(clap::Args)]
+struct SolveArgs {
+    ///Matrix A(.csv, .npy, .mtx or .npz)
+    #[arg(short, long)]
+    matrix: String,
+    ///Measurement vector y(.csv, .npy, .mtx or .npz)
+    #[arg(short, long)]
+    y: String,
+    ///Algorithm name(see `list`)
+    #[arg(short, long)]
+    alg: String,
+    ///Output file of the recovered coefficients x(.csv, .npy, .mtx or .npz)
+    #[arg(short, long)]
+    output: String,
+    ///Output file of the solve report. If it is not given, the report is printed.
+    #[arg(short, long)]
+    report: Option<String>,
+    #[arg(long, default_value_t = 1000)]
+    iter_num: usize,
+    #[arg(long, default_value_t = 1e-6)]
+    threshold: f64,
+    #[arg(long, default_value_t = 1e-2)]
+    lambda: f64,
+    #[arg(long, default_value_t = 1e-4)]
+    upsilon: f64,
+    #[arg(long, default_value_t = 0.5)]
+    proj_ratio: f64,
+    #[arg(long)]
+    support_size: Option<usize>,
+    ///Noise standard deviation which gives delta of dantzig. If it is not given, lambda is used as delta.
+    #[arg(long)]
+    noise_std: Option<f64>,
+    ///Values whose absolute values are less than supp_err_range / 2 are treated as zero in the report
+    #[arg(long, default_value_t = 1e-8)]
+    supp_err_range: f64,
+}
+
+fn solve(args: &SolveArgs) -> Result<()> {
+    let mat = read_matrix(&args.matrix)?;
+    let y = read_vector(&args.y)?;
+    let alg = build_alg(args)?;
+
+    let start = Instant::now();
+    let (x, iter_num) = alg.solve_with_iter(&mat, &y)?;
+    let runtime_sec = start.elapsed().as_secs_f64();
+
+    write_vector(&args.output, &x)?;
+
+    let mut report = String::new();
+    writeln!(report, "algorithm: {}", args.alg)?;
+    writeln!(
+        report,
+        "matrix: {} ({}x{})",
+        args.matrix,
+        mat.nrows(),
+        mat.ncols()
+    )?;
+    writeln!(report, "y: {} ({})", args.y, y.len())?;
+    writeln!(report, "output: {}", args.output)?;
+    match iter_num {
+        Some(iter_num) => writeln!(report, "iterations: {}", iter_num)?,
+        None => writeln!(report, "iterations: -")?,
+    }
+    writeln!(report, "runtime_sec: {}", runtime_sec)?;
+    writeln!(report, "residual_l2: {}", (&y - &mat.dot(&x)).norm_l2())?;
+    writeln!(report, "x_l1: {}", x.norm_l1())?;
+    writeln!(
+        report,
+        "support_size: {}",
+        support(&x, args.supp_err_range).len()
+    )?;
+
+    match &args.report {
+        Some(path) => fs::write(path, report)?,
+        None => print!("{}", report),
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match &cli.command {
+        Command::Solve(args) => solve(args),
+        Command::List => {
+            for (name, description) in ALGORITHMS {
+                println!("{:<12} {}", name, description);
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use super::*;
+use ndarray::prelude::*;
+use std::env;
+
+fn temp_path(name: &str) -> String {
+    env::temp_dir()
+        .join(format!(
+            "sparse_modeling_cli_{}_{}",
+            std::process::id(),
+            name
+        ))
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn solve_args(args: &[&str]) -> SolveArgs {
+    let cli = Cli::try_parse_from(args).unwrap();
+    match cli.command {
+        Command::Solve(args) => args,
+        Command::List => panic!("solve command is expected"),
+    }
+}
+
+#[test]
+fn parse_test() {
+    let args = solve_args(&[
+        "sparse_modeling",
+        "solve",
+        "-m",
+        "a.csv",
+        "-y",
+        "y.npy",
+        "-a",
+        "fista",
+        "-o",
+        "x.mtx",
+        "--lambda",
+        "0.5",
+    ]);
+    assert_eq!(args.matrix, "a.csv");
+    assert_eq!(args.y, "y.npy");
+    assert_eq!(args.alg, "fista");
+    assert_eq!(args.output, "x.mtx");
+    assert_eq!(args.report, None);
+    assert_eq!(args.iter_num, 1000);
+    assert_eq!(args.lambda, 0.5);
+
+    let cli = Cli::try_parse_from(["sparse_modeling", "list"]).unwrap();
+    assert!(matches!(cli.command, Command::List));
+    assert!(Cli::try_parse_from(["sparse_modeling", "solve", "-m", "a.csv"]).is_err());
+}
+
+#[test]
+fn build_alg_test() {
+    for (name, _) in ALGORITHMS {
+        let args = solve_args(&[
+            "sparse_modeling",
+            "solve",
+            "-m",
+            "a.csv",
+            "-y",
+            "y.csv",
+            "-a",
+            name,
+            "-o",
+            "x.csv",
+            "--support-size",
+            "1",
+        ]);
+        assert!(build_alg(&args).is_ok(), "{}", name);
+    }
+    let args = solve_args(&[
+        "sparse_modeling",
+        "solve",
+        "-m",
+        "a.csv",
+        "-y",
+        "y.csv",
+        "-a",
+        "unknown",
+        "-o",
+        "x.csv",
+    ]);
+    assert!(build_alg(&args).is_err());
+}
+
+#[test]
+fn solve_test() {
+    let mat = array![[1., 0., 0., 0.5], [0., 1., 0., 0.5], [0., 0., 1., 0.5]];
+    let x = array![0., 2., 0., 0.];
+    let y = mat.dot(&x);
+
+    let mat_path = temp_path("a.csv");
+    let y_path = temp_path("y.csv");
+    let x_path = temp_path("x.csv");
+    let report_path = temp_path("report.txt");
+    write_csv_matrix(&mat_path, &mat).unwrap();
+    write_csv_vector(&y_path, &y).unwrap();
+
+    let args = solve_args(&[
+        "sparse_modeling",
+        "solve",
+        "-m",
+        &mat_path,
+        "-y",
+        &y_path,
+        "-a",
+        "omp",
+        "-o",
+        &x_path,
+        "-r",
+        &report_path,
+    ]);
+    solve(&args).unwrap();
+
+    let result = read_vector(&x_path).unwrap();
+    let report = fs::read_to_string(&report_path).unwrap();
+    for path in [&mat_path, &y_path, &x_path, &report_path] {
+        fs::remove_file(path).unwrap();
+    }
+    assert!((&result - &x).norm_max() < 1e-8);
+    assert!(report.contains("algorithm: omp"));
+    assert!(report.contains("(3x4)"));
+    assert!(report.contains("support_size: 1"));
+}
@@ -0,0 +1,100 @@
+//! # GMRES
+//!
+//! Restarted GMRES(m) for general square matrices.
+//! iter_num is the total number of Arnoldi steps and it stops when ||r|| / ||y|| < threshold.
+use super::{check_square_sys, KrylovResult};
+use crate::prelude::*;
+
+pub fn gmres<M: LinearOperator + ?Sized>(
+    mat: &M,
+    y: &Array1<f64>,
+    restart: usize,
+    iter_num: usize,
+    threshold: f64,
+) -> Result<KrylovResult> {
+    check_square_sys(mat, y)?;
+    if restart == 0 {
+        return Err(anyhow!("restart is needed to be positive"));
+    }
+
+    //initialization
+    let n = y.len();
+    let mut x: Array1<f64> = Array::zeros(n);
+    let y_norm = y.norm_l2();
+    let mut residuals = vec![y_norm];
+    if y_norm == 0. {
+        return Ok(KrylovResult {
+            x,
+            iter_num: 0,
+            residuals,
+        });
+    }
+
+    let mut iter = 0;
+    while iter < iter_num {
+        let r = y - &mat.mul_vec(&x);
+        let r_norm = r.norm_l2();
+        if r_norm / y_norm < threshold {
+            break;
+        }
+        //Arnoldi with Givens rotations
+        let mut vs = vec![r / r_norm];
+        let mut h: Array2<f64> = Array::zeros((restart + 1, restart));
+        let mut cs: Vec<f64> = vec![];
+        let mut sn: Vec<f64> = vec![];
+        let mut g = vec![r_norm];
+        let mut converged = false;
+        for k in 0..restart {
+            iter += 1;
+            let mut w = mat.mul_vec(&vs[k]);
+            for (i, v) in vs.iter().enumerate() {
+                h[[i, k]] = v.dot(&w);
+                w = w - h[[i, k]] * v;
+            }
+            h[[k + 1, k]] = w.norm_l2();
+
+            for i in 0..k {
+                let temp = cs[i] * h[[i, k]] + sn[i] * h[[i + 1, k]];
+                h[[i + 1, k]] = -sn[i] * h[[i, k]] + cs[i] * h[[i + 1, k]];
+                h[[i, k]] = temp;
+            }
+            let denom = h[[k, k]].hypot(h[[k + 1, k]]);
+            cs.push(h[[k, k]] / denom);
+            sn.push(h[[k + 1, k]] / denom);
+            let breakdown = h[[k + 1, k]] <= F64_EPS * denom;
+            if !breakdown {
+                vs.push(w / h[[k + 1, k]]);
+            }
+            h[[k, k]] = denom;
+            h[[k + 1, k]] = 0.;
+            g.push(-sn[k] * g[k]);
+            g[k] *= cs[k];
+
+            residuals.push(g[k + 1].abs());
+            if g[k + 1].abs() / y_norm < threshold || breakdown || iter == iter_num {
+                converged = g[k + 1].abs() / y_norm < threshold || breakdown;
+                break;
+            }
+        }
+
+        //x += V_k H_k^(-1) g
+        let k = cs.len();
+        let mut coef = vec![0.; k];
+        for i in (0..k).rev() {
+            let sum: f64 = (i + 1..k).map(|j| h[[i, j]] * coef[j]).sum();
+            coef[i] = (g[i] - sum) / h[[i, i]];
+        }
+        for (c, v) in coef.iter().zip(vs.iter()) {
+            x = x + *c * v;
+        }
+        if converged {
+            break;
+        }
+    }
+
+    Ok(KrylovResult {
+        x,
+        iter_num: iter,
+        residuals,
+    })
+}
@@ -0,0 +1,213 @@
+//! # LSQR, LSMR
+//!
+//! Least squares(minimize ||y - Ax||_(2)) by Golub-Kahan bidiagonalization started from x = 0,
+//! so they converge to the minimum norm solution.
+//! LSQR is CG on A^T A x = A^T y, and LSMR is MINRES on it(||A^T r|| decreases monotonically).
+//! They stop when ||r|| / ||y|| < threshold or ||A^T r|| / ||A^T y|| < threshold.
+use super::KrylovResult;
+use crate::prelude::*;
+
+fn check_ls_sys<M: LinearOperator + ?Sized>(mat: &M, y: &Array1<f64>) -> Result<()> {
+    if mat.shape().0 != y.len() {
+        return Err(anyhow!("mat's row size and y's size are different"));
+    }
+    Ok(())
+}
+
+pub fn lsqr<M: LinearOperator + ?Sized>(
+    mat: &M,
+    y: &Array1<f64>,
+    iter_num: usize,
+    threshold: f64,
+) -> Result<KrylovResult> {
+    check_ls_sys(mat, y)?;
+
+    //initialization
+    let mut x: Array1<f64> = Array::zeros(mat.shape().1);
+    let mut beta = y.norm_l2();
+    let mut residuals = vec![beta];
+    if beta == 0. {
+        return Ok(KrylovResult {
+            x,
+            iter_num: 0,
+            residuals,
+        });
+    }
+    let mut u = y / beta;
+    let mut v = mat.t_mul_vec(&u);
+    let mut alpha = v.norm_l2();
+    if alpha == 0. {
+        return Ok(KrylovResult {
+            x,
+            iter_num: 0,
+            residuals,
+        });
+    }
+    v /= alpha;
+    let init_grad = alpha * beta;
+    let mut w = v.clone();
+    let mut phibar = beta;
+    let mut rhobar = alpha;
+    let y_norm = beta;
+
+    let mut iter = 0;
+    for _ in 0..iter_num {
+        iter += 1;
+        //bidiagonalization
+        u = mat.mul_vec(&v) - alpha * &u;
+        beta = u.norm_l2();
+        if beta > 0. {
+            u /= beta;
+            v = mat.t_mul_vec(&u) - beta * &v;
+            alpha = v.norm_l2();
+            if alpha > 0. {
+                v /= alpha;
+            }
+        }
+
+        //QR by Givens rotation
+        let rho = rhobar.hypot(beta);
+        let c = rhobar / rho;
+        let s = beta / rho;
+        let theta = s * alpha;
+        rhobar = -c * alpha;
+        let phi = c * phibar;
+        phibar *= s;
+
+        x = x + (phi / rho) * &w;
+        w = &v - (theta / rho) * &w;
+
+        //||r|| = phibar, ||A^T r|| = phibar alpha |c|
+        residuals.push(phibar);
+        if phibar / y_norm < threshold || phibar * alpha * c.abs() / init_grad < threshold {
+            break;
+        }
+        if beta == 0. || alpha == 0. {
+            break;
+        }
+    }
+
+    Ok(KrylovResult {
+        x,
+        iter_num: iter,
+        residuals,
+    })
+}
+
+pub fn lsmr<M: LinearOperator + ?Sized>(
+    mat: &M,
+    y: &Array1<f64>,
+    iter_num: usize,
+    threshold: f64,
+) -> Result<KrylovResult> {
+    check_ls_sys(mat, y)?;
+
+    //initialization
+    let mut x: Array1<f64> = Array::zeros(mat.shape().1);
+    let mut beta = y.norm_l2();
+    if beta == 0. {
+        return Ok(KrylovResult {
+            x,
+            iter_num: 0,
+            residuals: vec![0.],
+        });
+    }
+    let mut u = y / beta;
+    let mut v = mat.t_mul_vec(&u);
+    let mut alpha = v.norm_l2();
+    let mut residuals = vec![alpha * beta];
+    if alpha == 0. {
+        return Ok(KrylovResult {
+            x,
+            iter_num: 0,
+            residuals,
+        });
+    }
+    v /= alpha;
+    let y_norm = beta;
+    let init_grad = alpha * beta;
+
+    let mut zetabar = alpha * beta;
+    let mut alphabar = alpha;
+    let mut rho: f64 = 1.;
+    let mut rhobar: f64 = 1.;
+    let mut cbar = 1.;
+    let mut sbar = 0.;
+    let mut h = v.clone();
+    let mut hbar: Array1<f64> = Array::zeros(mat.shape().1);
+
+    //for the estimate of ||r||
+    let mut betadd = beta;
+    let mut betad = 0.;
+    let mut rhodold: f64 = 1.;
+    let mut tautildeold = 0.;
+    let mut thetatilde = 0.;
+    let mut zeta = 0.;
+
+    let mut iter = 0;
+    for _ in 0..iter_num {
+        iter += 1;
+        //bidiagonalization
+        u = mat.mul_vec(&v) - alpha * &u;
+        beta = u.norm_l2();
+        if beta > 0. {
+            u /= beta;
+            v = mat.t_mul_vec(&u) - beta * &v;
+            alpha = v.norm_l2();
+            if alpha > 0. {
+                v /= alpha;
+            }
+        }
+
+        //rotations of the bidiagonal matrix
+        let rhoold = rho;
+        rho = alphabar.hypot(beta);
+        let c = alphabar / rho;
+        let s = beta / rho;
+        let thetanew = s * alpha;
+        alphabar = c * alpha;
+
+        let rhobarold = rhobar;
+        let zetaold = zeta;
+        let thetabar = sbar * rho;
+        let rhotemp = cbar * rho;
+        rhobar = rhotemp.hypot(thetanew);
+        cbar = rhotemp / rhobar;
+        sbar = thetanew / rhobar;
+        zeta = cbar * zetabar;
+        zetabar *= -sbar;
+
+        hbar = &h - (thetabar * rho / (rhoold * rhobarold)) * &hbar;
+        x = x + (zeta / (rho * rhobar)) * &hbar;
+        h = &v - (thetanew / rho) * &h;
+
+        //||r||
+        let betahat = c * betadd;
+        betadd *= -s;
+        let thetatildeold = thetatilde;
+        let rhotildeold = rhodold.hypot(thetabar);
+        let ctildeold = rhodold / rhotildeold;
+        let stildeold = thetabar / rhotildeold;
+        thetatilde = stildeold * rhobar;
+        rhodold = ctildeold * rhobar;
+        betad = -stildeold * betad + ctildeold * betahat;
+        tautildeold = (zetaold - thetatildeold * tautildeold) / rhotildeold;
+        let taud = (zeta - thetatilde * tautildeold) / rhodold;
+        let r_norm = ((betad - taud).powi(2) + betadd * betadd).sqrt();
+
+        //||A^T r|| = |zetabar|
+        residuals.push(zetabar.abs());
+        if r_norm / y_norm < threshold || zetabar.abs() / init_grad < threshold {
+            break;
+        }
+        if beta == 0. || alpha == 0. {
+            break;
+        }
+    }
+
+    Ok(KrylovResult {
+        x,
+        iter_num: iter,
+        residuals,
+    })
+}
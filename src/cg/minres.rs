@@ -0,0 +1,85 @@
+//! # MINRES
+//!
+//! Minimum residual method for symmetric(possibly indefinite) matrices.
+//! It stops when ||r|| / ||y|| < threshold.
+use super::{check_square_sys, KrylovResult};
+use crate::prelude::*;
+
+pub fn minres<M: LinearOperator + ?Sized>(
+    mat: &M,
+    y: &Array1<f64>,
+    iter_num: usize,
+    threshold: f64,
+) -> Result<KrylovResult> {
+    check_square_sys(mat, y)?;
+
+    //initialization
+    let n = y.len();
+    let mut x: Array1<f64> = Array::zeros(n);
+    let y_norm = y.norm_l2();
+    let mut residuals = vec![y_norm];
+    if y_norm == 0. {
+        return Ok(KrylovResult {
+            x,
+            iter_num: 0,
+            residuals,
+        });
+    }
+    //r1, r2: unnormalized Lanczos vectors
+    let mut r1 = y.clone();
+    let mut r2 = y.clone();
+    let mut beta = y_norm;
+    let mut oldb = 0.;
+    let mut dbar = 0.;
+    let mut epsln = 0.;
+    let mut phibar = y_norm;
+    let mut cs = -1.;
+    let mut sn = 0.;
+    let mut w: Array1<f64> = Array::zeros(n);
+    let mut w2: Array1<f64> = Array::zeros(n);
+
+    let mut iter = 0;
+    for _ in 0..iter_num {
+        iter += 1;
+        //Lanczos
+        let v = &r2 / beta;
+        let mut z = mat.mul_vec(&v);
+        if iter > 1 {
+            z = z - (beta / oldb) * &r1;
+        }
+        let alpha = v.dot(&z);
+        z = z - (alpha / beta) * &r2;
+        r1 = r2;
+        r2 = z;
+        oldb = beta;
+        beta = r2.norm_l2();
+
+        //QR by Givens rotation
+        let oldeps = epsln;
+        let delta = cs * dbar + sn * alpha;
+        let gbar = sn * dbar - cs * alpha;
+        epsln = sn * beta;
+        dbar = -cs * beta;
+        let gamma = gbar.hypot(beta).max(f64::EPSILON);
+        cs = gbar / gamma;
+        sn = beta / gamma;
+        let phi = cs * phibar;
+        phibar *= sn;
+
+        let w1 = w2;
+        w2 = w;
+        w = (&v - oldeps * &w1 - delta * &w2) / gamma;
+        x = x + phi * &w;
+
+        residuals.push(phibar);
+        if phibar / y_norm < threshold || beta == 0. {
+            break;
+        }
+    }
+
+    Ok(KrylovResult {
+        x,
+        iter_num: iter,
+        residuals,
+    })
+}
@@ -0,0 +1,146 @@
+//! # CG
+//! 
+//! conjugate gradient algorithm and other Krylov subspace solvers.
+//! pcg: preconditioned CG for symmetric positive definite matrices.
+//! lsqr, lsmr: least squares(minimize ||y - Ax||_(2)) for rectangular matrices.
+//! minres: symmetric(possibly indefinite) matrices.
+//! gmres: general square matrices.
+//! Matrices are given as LinearOperators and the solvers return KrylovResults.
+mod gmres;
+mod lsqr;
+mod minres;
+mod preconditioner;
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+pub use gmres::gmres;
+pub use lsqr::{lsmr, lsqr};
+pub use minres::minres;
+pub use preconditioner::{
+    IdentityPreconditioner, IncompleteCholesky, JacobiPreconditioner, Preconditioner,
+};
+
+pub struct KrylovResult {
+    pub x: Array1<f64>,
+    pub iter_num: usize,
+    ///residual norms of iterations(||A^T r|| for lsmr)
+    pub residuals: Vec<f64>,
+}
+
+pub fn conjugate_gradient(
+    mat: &Array2<f64>,
+    y: &Array1<f64>,
+    iter_num: usize,
+    threshold: f64,
+) -> Result<Array1<f64>> {
+    check_square_sys(mat, y)?;
+    if !is_symmetric(mat) {
+        return Err(anyhow!("mat is not symmetric"));
+    }
+
+    Ok(conjugate_gradient_by(
+        |d| mat.dot(d),
+        y,
+        iter_num,
+        threshold,
+    ))
+}
+
+///Conjugate gradient for a symmetric positive definite matrix given as a function d -> mat * d.
+pub fn conjugate_gradient_by<F: Fn(&Array1<f64>) -> Array1<f64>>(
+    apply: F,
+    y: &Array1<f64>,
+    iter_num: usize,
+    threshold: f64,
+) -> Array1<f64> {
+    pcg_by(apply, &IdentityPreconditioner, y, iter_num, threshold).x
+}
+
+///Preconditioned conjugate gradient for a symmetric positive definite matrix.
+///It stops when ||r||_(2) / ||y||_(2) < threshold.
+pub fn pcg<M: LinearOperator + ?Sized, P: Preconditioner + ?Sized>(
+    mat: &M,
+    precond: &P,
+    y: &Array1<f64>,
+    iter_num: usize,
+    threshold: f64,
+) -> Result<KrylovResult> {
+    check_square_sys(mat, y)?;
+    Ok(pcg_by(|d| mat.mul_vec(d), precond, y, iter_num, threshold))
+}
+
+///Preconditioned conjugate gradient for a matrix given as a function d -> mat * d.
+pub fn pcg_by<F: Fn(&Array1<f64>) -> Array1<f64>, P: Preconditioner + ?Sized>(
+    apply: F,
+    precond: &P,
+    y: &Array1<f64>,
+    iter_num: usize,
+    threshold: f64,
+) -> KrylovResult {
+    //initialization
+    let mut x: Array1<f64> = ArrayBase::zeros(y.shape()[0]);
+    let mut r = y.clone();
+    let mut z = precond.apply(&r);
+    let mut rz = r.dot(&z);
+    let mut d = z.clone();
+    let y_norm = y.norm_l2();
+    let mut residuals = vec![y_norm];
+    if y_norm == 0. {
+        return KrylovResult {
+            x,
+            iter_num: 0,
+            residuals,
+        };
+    }
+
+    let mut iter = 0;
+    for _ in 0..iter_num {
+        iter += 1;
+        let mat_d = apply(&d);
+        let a = rz / d.dot(&mat_d);
+        x = x + a * &d;
+        r = r - a * mat_d;
+        residuals.push(r.norm_l2());
+
+        if r.norm_l2() / y_norm < threshold {
+            break;
+        }
+
+        z = precond.apply(&r);
+        let prev_rz = rz;
+        rz = r.dot(&z);
+        d = &z + rz / prev_rz * d;
+    }
+
+    KrylovResult {
+        x,
+        iter_num: iter,
+        residuals,
+    }
+}
+
+fn check_square_sys<M: LinearOperator + ?Sized>(mat: &M, y: &Array1<f64>) -> Result<()> {
+    if mat.shape() != (y.len(), y.len()) {
+        return Err(anyhow!(format!(
+            "mat's shape is {:?}, it is needed to be ({}, {})",
+            mat.shape(),
+            y.len(),
+            y.len()
+        )));
+    }
+    Ok(())
+}
+
+//u^T (A v) = v^T (A u) for symmetric matrices, checked with two probe vectors
+fn is_symmetric<M: LinearOperator + ?Sized>(mat: &M) -> bool {
+    let n = mat.shape().0;
+    let mut rng = StdRng::seed_from_u64(0);
+    let u: Array1<f64> = Array::from_shape_fn(n, |_| rng.sample(StandardNormal));
+    let v: Array1<f64> = Array::from_shape_fn(n, |_| rng.sample(StandardNormal));
+    let mat_u = mat.mul_vec(&u);
+    let mat_v = mat.mul_vec(&v);
+    let scale = mat_u.norm_l2() * v.norm_l2() + mat_v.norm_l2() * u.norm_l2();
+    (u.dot(&mat_v) - v.dot(&mat_u)).abs() <= F64_EPS * scale
+}
@@ -0,0 +1,142 @@
+//! # Preconditioner
+//!
+//! Preconditioners M(M^(-1) ≈ A^(-1)) of pcg.
+use crate::prelude::*;
+
+pub trait Preconditioner {
+    ///M^(-1) r
+    fn apply(&self, r: &Array1<f64>) -> Array1<f64>;
+}
+
+pub struct IdentityPreconditioner;
+
+impl Preconditioner for IdentityPreconditioner {
+    fn apply(&self, r: &Array1<f64>) -> Array1<f64> {
+        r.clone()
+    }
+}
+
+///M = diag(A)
+pub struct JacobiPreconditioner {
+    inv_diag: Array1<f64>,
+}
+
+impl JacobiPreconditioner {
+    pub fn new(diag: &Array1<f64>) -> Result<Self> {
+        if diag.iter().any(|d| *d <= 0.) {
+            return Err(anyhow!("diagonal elements are needed to be positive"));
+        }
+        Ok(Self {
+            inv_diag: diag.mapv(|d| 1. / d),
+        })
+    }
+    pub fn from_operator<M: LinearOperator + ?Sized>(mat: &M) -> Result<Self> {
+        let (row_size, column_size) = mat.shape();
+        if row_size != column_size {
+            return Err(anyhow!("mat is not square"));
+        }
+        Self::new(&mat.diagonal())
+    }
+}
+
+impl Preconditioner for JacobiPreconditioner {
+    fn apply(&self, r: &Array1<f64>) -> Array1<f64> {
+        &self.inv_diag * r
+    }
+}
+
+///M = L L^T, L is the incomplete Cholesky factor IC(0)
+///whose nonzero elements are restricted to the ones of the lower triangle of A.
+pub struct IncompleteCholesky {
+    //nonzero elements (column, value) of rows of L, the diagonal is the last one
+    rows: Vec<Vec<(usize, f64)>>,
+}
+
+impl IncompleteCholesky {
+    ///Only the lower triangle of mat is read. Dense matrices are converted by CsrMatrix::from_dense.
+    pub fn new(mat: &CsrMatrix) -> Result<Self> {
+        let (row_size, column_size) = mat.shape();
+        if row_size != column_size {
+            return Err(anyhow!("mat is not square"));
+        }
+        let (indptr, indices, data) = (mat.indptr(), mat.indices(), mat.data());
+
+        //row by row: l_ik = (a_ik - sum_(j<k) l_ij l_kj) / l_kk, l_ii = sqrt(a_ii - sum_(j<i) l_ij^2)
+        let mut rows: Vec<Vec<(usize, f64)>> = Vec::with_capacity(row_size);
+        for i in 0..row_size {
+            let mut lower: Vec<(usize, f64)> = (indptr[i]..indptr[i + 1])
+                .filter(|k| indices[*k] <= i)
+                .map(|k| (indices[k], data[k]))
+                .collect();
+            lower.sort_by_key(|(j, _)| *j);
+            lower.dedup_by(|(j, v), (prev_j, prev_v)| {
+                if j == prev_j {
+                    *prev_v += *v;
+                }
+                j == prev_j
+            });
+
+            let mut row: Vec<(usize, f64)> = Vec::with_capacity(lower.len());
+            let mut diag = 0.;
+            for (k, a_ik) in lower {
+                if k == i {
+                    diag = a_ik - row.iter().map(|(_, v)| v * v).sum::<f64>();
+                    break;
+                }
+                let (l_kk, l_k) = rows[k].split_last().unwrap();
+                row.push((k, (a_ik - sparse_dot(&row, l_k)) / l_kk.1));
+            }
+            if diag <= 0. {
+                return Err(anyhow!(format!(
+                    "incomplete Cholesky factorization breaks down at {}",
+                    i
+                )));
+            }
+            row.push((i, diag.sqrt()));
+            rows.push(row);
+        }
+        Ok(Self { rows })
+    }
+}
+
+//dot product of sparse vectors whose elements are sorted by index
+fn sparse_dot(a: &[(usize, f64)], b: &[(usize, f64)]) -> f64 {
+    let (mut i, mut j) = (0, 0);
+    let mut sum = 0.;
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            cmp::Ordering::Less => i += 1,
+            cmp::Ordering::Greater => j += 1,
+            cmp::Ordering::Equal => {
+                sum += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    sum
+}
+
+impl Preconditioner for IncompleteCholesky {
+    fn apply(&self, r: &Array1<f64>) -> Array1<f64> {
+        let n = r.len();
+        //L z = r
+        let mut z = r.clone();
+        for i in 0..n {
+            let (diag, others) = self.rows[i].split_last().unwrap();
+            for (j, v) in others {
+                z[i] -= v * z[*j];
+            }
+            z[i] /= diag.1;
+        }
+        //L^T x = z
+        for i in (0..n).rev() {
+            let (diag, others) = self.rows[i].split_last().unwrap();
+            z[i] /= diag.1;
+            for (j, v) in others {
+                z[*j] -= v * z[i];
+            }
+        }
+        z
+    }
+}
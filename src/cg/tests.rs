@@ -0,0 +1,133 @@
+use super::*;
+
+//symmetric positive definite band matrix whose diagonal elements grow
+fn band_spd_mat(n: usize) -> Array2<f64> {
+    let mut mat = Array::zeros((n, n));
+    for i in 0..n {
+        mat[[i, i]] = 2. * (1. + i as f64);
+        if i + 1 < n {
+            mat[[i, i + 1]] = -1.;
+            mat[[i + 1, i]] = -1.;
+        }
+        if i + 10 < n {
+            mat[[i, i + 10]] = -0.5;
+            mat[[i + 10, i]] = -0.5;
+        }
+    }
+    mat
+}
+
+#[test]
+fn pcg_test() {
+    let mut rng = test_rng();
+    let mat = band_spd_mat(100);
+    let x: Array1<f64> = Array::from_shape_fn(100, |_| rng.gen_range(-1.0..1.0));
+    let y = mat.dot(&x);
+
+    let cg_result = pcg(&mat, &IdentityPreconditioner, &y, 1000, 1e-12).unwrap();
+    let jacobi = JacobiPreconditioner::from_operator(&mat).unwrap();
+    let jacobi_result = pcg(&mat, &jacobi, &y, 1000, 1e-12).unwrap();
+    let csr = CsrMatrix::from_dense(&mat);
+    let ic = IncompleteCholesky::new(&csr).unwrap();
+    let ic_result = pcg(&csr, &ic, &y, 1000, 1e-12).unwrap();
+    for result in [&cg_result, &jacobi_result, &ic_result] {
+        println!("iter: {}", result.iter_num);
+        assert!((&result.x - &x).norm_l2() < 1e-8);
+        assert_eq!(result.residuals.len(), result.iter_num + 1);
+    }
+    //preconditioners reduce iterations
+    assert!(jacobi_result.iter_num < cg_result.iter_num);
+    assert!(ic_result.iter_num < jacobi_result.iter_num);
+
+    //IC(0) of a dense matrix is the exact Cholesky factorization
+    let a = rand_gaussian_mat(&mut rng, (30, 30), 1.);
+    let dense = a.t().dot(&a) + Array2::<f64>::eye(30);
+    let y = dense.dot(&x.slice(s![..30]));
+    let result = pcg(
+        &dense,
+        &IncompleteCholesky::new(&CsrMatrix::from_dense(&dense)).unwrap(),
+        &y,
+        100,
+        1e-12,
+    )
+    .unwrap();
+    assert_eq!(result.iter_num, 1);
+
+    assert!(conjugate_gradient(&mat, &mat.dot(&x), 1000, 1e-12).is_ok());
+    let mut non_symmetric = mat.clone();
+    non_symmetric[[0, 1]] = 5.;
+    assert!(conjugate_gradient(&non_symmetric, &y, 10, 1e-12).is_err());
+    assert!(pcg(&mat, &IdentityPreconditioner, &y, 10, 1e-12).is_err());
+    assert!(JacobiPreconditioner::new(&array![1., 0.]).is_err());
+    assert!(JacobiPreconditioner::from_operator(&Array2::<f64>::ones((2, 3))).is_err());
+    let indefinite = CsrMatrix::from_dense(&array![[1., 2.], [2., 1.]]);
+    assert!(IncompleteCholesky::new(&indefinite).is_err());
+}
+
+#[test]
+fn least_squares_test() {
+    let mut rng = test_rng();
+    //overdetermined and inconsistent: the solution of the normal equation
+    let mat = rand_gaussian_mat(&mut rng, (80, 30), 1.);
+    let y: Array1<f64> = Array::from_shape_fn(80, |_| rng.gen_range(-1.0..1.0));
+    let exact = conjugate_gradient(&mat.t().dot(&mat), &mat.t().dot(&y), 1000, 1e-15).unwrap();
+    let lsqr_result = lsqr(&mat, &y, 1000, 1e-14).unwrap();
+    let lsmr_result = lsmr(&mat, &y, 1000, 1e-14).unwrap();
+    assert!((&lsqr_result.x - &exact).norm_l2() < 1e-10);
+    assert!((&lsmr_result.x - &exact).norm_l2() < 1e-10);
+    //lsqr's residuals are ||r|| and lsmr's ones(||A^T r||) are non-increasing
+    let r_norm = (&y - &mat.dot(&lsqr_result.x)).norm_l2();
+    assert!((lsqr_result.residuals.last().unwrap() - r_norm).abs() < 1e-8);
+    assert!(lsmr_result
+        .residuals
+        .windows(2)
+        .all(|w| w[1] <= w[0] * (1. + 1e-12)));
+
+    //underdetermined: the minimum norm solution
+    let mat = rand_gaussian_mat(&mut rng, (20, 50), 1.);
+    let y = mat.dot(&Array::from_shape_fn(50, |_| rng.gen_range(-1.0..1.0)));
+    let exact = mat
+        .t()
+        .dot(&conjugate_gradient(&mat.dot(&mat.t()), &y, 1000, 1e-15).unwrap());
+    let csc = CscMatrix::from_dense(&mat);
+    assert!((lsqr(&csc, &y, 1000, 1e-14).unwrap().x - &exact).norm_l2() < 1e-10);
+    assert!((lsmr(&csc, &y, 1000, 1e-14).unwrap().x - &exact).norm_l2() < 1e-10);
+    assert!(lsqr(&mat, &Array::zeros(3), 10, 1e-14).is_err());
+}
+
+#[test]
+fn minres_gmres_test() {
+    let mut rng = test_rng();
+    let x: Array1<f64> = Array::from_shape_fn(60, |_| rng.gen_range(-1.0..1.0));
+
+    //symmetric indefinite
+    let a = rand_gaussian_mat(&mut rng, (30, 30), 1.);
+    let mut indefinite = a.t().dot(&a) + Array2::<f64>::eye(30);
+    for i in 0..15 {
+        indefinite[[i, i]] -= 60.;
+    }
+    let y = indefinite.dot(&x.slice(s![..30]));
+    let minres_result = minres(&indefinite, &y, 1000, 1e-12).unwrap();
+    println!("minres iter: {}", minres_result.iter_num);
+    assert!((&y - &indefinite.dot(&minres_result.x)).norm_l2() < 1e-10 * y.norm_l2());
+    assert!(minres_result
+        .residuals
+        .windows(2)
+        .all(|w| w[1] <= w[0] * (1. + 1e-12)));
+
+    //non-symmetric(the iterations are compared on a fixed matrix)
+    let mat =
+        rand_gaussian_mat(&mut SeededRng::new(0), (60, 60), 1.) + 10. * Array2::<f64>::eye(60);
+    let y = mat.dot(&x);
+    let mut iters = vec![];
+    for restart in [60, 10, 5] {
+        let gmres_result = gmres(&mat, &y, restart, 2000, 1e-12).unwrap();
+        println!("gmres({}) iter: {}", restart, gmres_result.iter_num);
+        assert!((&gmres_result.x - &x).norm_l2() < 1e-8);
+        assert_eq!(gmres_result.residuals.len(), gmres_result.iter_num + 1);
+        iters.push(gmres_result.iter_num);
+    }
+    //restart slows down the convergence
+    assert!(iters[0] <= iters[1] && iters[1] <= iters[2]);
+    assert!(gmres(&mat, &y, 0, 10, 1e-12).is_err());
+}
@@ -0,0 +1,64 @@
+//! # Block Threshold
+//!
+//! Block soft thresholding. Each block of block_size elements is shrunk by its l2 norm.
+use crate::prelude::*;
+
+pub struct BlockThreshold {
+    block_size: usize,
+    alpha: f64,
+}
+
+impl BlockThreshold {
+    #[allow(dead_code)]
+    pub fn new(block_size: usize, alpha: f64) -> Result<Self> {
+        if block_size == 0 {
+            return Err(anyhow!("block_size is needed to be positive"));
+        }
+        Ok(Self { block_size, alpha })
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, block_size: usize, alpha: f64) -> Result<()> {
+        if block_size == 0 {
+            return Err(anyhow!("block_size is needed to be positive"));
+        }
+        self.block_size = block_size;
+        self.alpha = alpha;
+        Ok(())
+    }
+}
+
+impl Denoiser for BlockThreshold {
+    fn denoise(&self, v: &Array1<f64>, sigma: f64) -> Array1<f64> {
+        let mut x = v.clone();
+        for mut block in x.exact_chunks_mut(self.block_size) {
+            let norm = block.norm_l2();
+            let scale = if norm > 0. {
+                st(self.alpha * sigma, norm) / norm
+            } else {
+                0.
+            };
+            block *= scale;
+        }
+        let rest = v.len() - v.len() % self.block_size;
+        let mut tail = x.slice_mut(s![rest..]);
+        let norm = tail.norm_l2();
+        if norm > 0. {
+            tail *= st(self.alpha * sigma, norm) / norm;
+        }
+        x
+    }
+    fn divergence(&self, v: &Array1<f64>, sigma: f64) -> f64 {
+        //trace of the jacobian of b -> b(1 - t/||b||) is B(1 - t/||b||) + t/||b|| if ||b|| > t
+        let threshold = self.alpha * sigma;
+        v.axis_chunks_iter(Axis(0), self.block_size)
+            .map(|block| {
+                let norm = block.norm_l2();
+                if norm > threshold {
+                    block.len() as f64 * (1. - threshold / norm) + threshold / norm
+                } else {
+                    0.
+                }
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,59 @@
+//! # Denoiser
+//!
+//! 'denoiser' is a collection of denoisers which can be plugged in iterative algorithms
+//! (PnpProxGrad, PnpAdmm and Damp) in place of soft thresholding.
+mod block;
+mod nlm;
+#[cfg(test)]
+mod tests;
+mod tv;
+mod wavelet;
+
+use crate::prelude::*;
+
+pub use block::BlockThreshold;
+pub use nlm::NonLocalMeans;
+pub use tv::TvDenoiser;
+pub use wavelet::HaarShrinkage;
+
+pub trait Denoiser {
+    ///Denoise v whose noise level(standard deviation or threshold scale) is sigma.
+    fn denoise(&self, v: &Array1<f64>, sigma: f64) -> Array1<f64>;
+    ///Divergence Σ_i ∂denoise(v)_i/∂v_i, used for the Onsager correction of D-AMP.
+    ///It is estimated by a finite difference along a fixed random ±1 direction by default.
+    fn divergence(&self, v: &Array1<f64>, sigma: f64) -> f64 {
+        let mut rng = StdRng::seed_from_u64(0);
+        let direction: Array1<f64> =
+            Array::from_shape_fn(v.len(), |_| if rng.gen::<bool>() { 1. } else { -1. });
+        let eps = (v.norm_max() * 1e-3).max(F64_EPS);
+        let perturbed = self.denoise(&(v + &(eps * &direction)), sigma);
+        direction.dot(&(perturbed - self.denoise(v, sigma))) / eps
+    }
+}
+
+///Soft thresholding with threshold alpha * sigma
+pub struct SoftThreshold {
+    alpha: f64,
+}
+
+impl SoftThreshold {
+    #[allow(dead_code)]
+    pub fn new(alpha: f64) -> Self {
+        Self { alpha }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, alpha: f64) {
+        self.alpha = alpha;
+    }
+}
+
+impl Denoiser for SoftThreshold {
+    fn denoise(&self, v: &Array1<f64>, sigma: f64) -> Array1<f64> {
+        st_array1(self.alpha * sigma, v)
+    }
+    fn divergence(&self, v: &Array1<f64>, sigma: f64) -> f64 {
+        v.iter()
+            .filter(|v_i| v_i.abs() > self.alpha * sigma)
+            .count() as f64
+    }
+}
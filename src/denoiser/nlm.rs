@@ -0,0 +1,60 @@
+//! # Non-Local Means
+//!
+//! Patch-based denoising of 1-D signals. Each element is replaced by the weighted mean of
+//! elements in the search window whose surrounding patches are similar.
+use crate::prelude::*;
+
+pub struct NonLocalMeans {
+    patch_radius: usize,
+    search_radius: usize,
+    h: f64,
+}
+
+impl NonLocalMeans {
+    #[allow(dead_code)]
+    pub fn new(patch_radius: usize, search_radius: usize, h: f64) -> Result<Self> {
+        if h <= 0. {
+            return Err(anyhow!(format!("h is {}, it is needed to be positive", h)));
+        }
+        Ok(Self {
+            patch_radius,
+            search_radius,
+            h,
+        })
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, patch_radius: usize, search_radius: usize, h: f64) -> Result<()> {
+        if h <= 0. {
+            return Err(anyhow!(format!("h is {}, it is needed to be positive", h)));
+        }
+        self.patch_radius = patch_radius;
+        self.search_radius = search_radius;
+        self.h = h;
+        Ok(())
+    }
+}
+
+impl Denoiser for NonLocalMeans {
+    fn denoise(&self, v: &Array1<f64>, sigma: f64) -> Array1<f64> {
+        let n = v.len() as isize;
+        if n == 0 || sigma <= 0. {
+            return v.clone();
+        }
+        let (p, w) = (self.patch_radius as isize, self.search_radius as isize);
+        //elements out of range are given by clamping the index
+        let at = |i: isize| v[i.clamp(0, n - 1) as usize];
+        let scale = self.h * self.h * sigma * sigma * (2 * p + 1) as f64;
+
+        Array::from_shape_fn(v.len(), |i| {
+            let i = i as isize;
+            let (mut sum, mut weight_sum) = (0., 0.);
+            for j in cmp::max(i - w, 0)..cmp::min(i + w + 1, n) {
+                let dist: f64 = (-p..=p).map(|k| (at(i + k) - at(j + k)).powi(2)).sum();
+                let weight = (-dist / scale).exp();
+                sum += weight * v[j as usize];
+                weight_sum += weight;
+            }
+            sum / weight_sum
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn denoiser_test() {
+    let mut rng = test_rng();
+    let size = 256;
+    let sigma = 0.1;
+    //piecewise constant signal
+    let signal: Array1<f64> = Array::from_shape_fn(size, |i| ((i / 32) % 3) as f64 - 1.);
+    let noise: Array1<f64> =
+        Array::from_shape_fn(size, |_| sigma * rng.sample::<f64, _>(StandardNormal));
+    let noisy = &signal + &noise;
+    let noisy_err = (&noisy - &signal).norm_l2();
+
+    let denoisers: Vec<(&str, Box<dyn Denoiser>)> = vec![
+        ("haar", Box::new(HaarShrinkage::new(5, 3.))),
+        ("nlm", Box::new(NonLocalMeans::new(2, 8, 1.).unwrap())),
+    ];
+    for (name, denoiser) in denoisers {
+        let err = (denoiser.denoise(&noisy, sigma) - &signal).norm_l2();
+        println!(
+            "{}|| noisy error: {}, denoised error: {}",
+            name, noisy_err, err
+        );
+        assert!(err < noisy_err);
+    }
+
+    //haar shrinkage without thresholding is the identity
+    let identity = HaarShrinkage::new(5, 0.).denoise(&noisy, sigma);
+    assert!((identity - &noisy).norm_l2() < 1e-10);
+}
+
+#[test]
+fn block_threshold_test() {
+    let v = array![3., 4., 0., 0.1, 1.];
+    let x = BlockThreshold::new(2, 1.).unwrap().denoise(&v, 1.);
+    assert!((x - array![2.4, 3.2, 0., 0., 0.]).norm_l2() < 1e-10);
+
+    //divergence of soft thresholding is the number of surviving elements
+    let soft = SoftThreshold::new(1.);
+    assert_eq!(soft.divergence(&array![3., -0.5, -2.], 1.), 2.);
+}
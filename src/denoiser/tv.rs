@@ -0,0 +1,31 @@
+//! # TV Denoiser
+//!
+//! 1-D total variation denoising with λ = alpha * sigma.
+use crate::prelude::*;
+use crate::tv::tv_denoise_1d;
+
+pub struct TvDenoiser {
+    alpha: f64,
+}
+
+impl TvDenoiser {
+    #[allow(dead_code)]
+    pub fn new(alpha: f64) -> Self {
+        Self { alpha }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, alpha: f64) {
+        self.alpha = alpha;
+    }
+}
+
+impl Denoiser for TvDenoiser {
+    fn denoise(&self, v: &Array1<f64>, sigma: f64) -> Array1<f64> {
+        tv_denoise_1d(v, self.alpha * sigma)
+    }
+    fn divergence(&self, v: &Array1<f64>, sigma: f64) -> f64 {
+        //the output is piecewise constant and each segment has one degree of freedom
+        let x = self.denoise(v, sigma);
+        1. + x.windows(2).into_iter().filter(|w| w[1] != w[0]).count() as f64
+    }
+}
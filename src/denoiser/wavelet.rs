@@ -0,0 +1,59 @@
+//! # Haar Shrinkage
+//!
+//! Soft thresholding of detail coefficients of the orthonormal Haar wavelet transform.
+//! The transform is repeated level_num times while the length of the approximation is even.
+use crate::prelude::*;
+
+pub struct HaarShrinkage {
+    level_num: usize,
+    alpha: f64,
+}
+
+impl HaarShrinkage {
+    #[allow(dead_code)]
+    pub fn new(level_num: usize, alpha: f64) -> Self {
+        Self { level_num, alpha }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, level_num: usize, alpha: f64) {
+        self.level_num = level_num;
+        self.alpha = alpha;
+    }
+}
+
+impl Denoiser for HaarShrinkage {
+    fn denoise(&self, v: &Array1<f64>, sigma: f64) -> Array1<f64> {
+        //forward transform([approximation, details of the coarsest level, ..., finest level])
+        let mut coef = v.clone();
+        let mut len = v.len();
+        let mut lengths = Vec::new();
+        for _ in 0..self.level_num {
+            if len < 2 || len % 2 != 0 {
+                break;
+            }
+            let half = len / 2;
+            let current = coef.slice(s![..len]).to_owned();
+            for i in 0..half {
+                coef[i] = (current[2 * i] + current[2 * i + 1]) / 2f64.sqrt();
+                coef[half + i] = (current[2 * i] - current[2 * i + 1]) / 2f64.sqrt();
+            }
+            lengths.push(len);
+            len = half;
+        }
+
+        //shrink details
+        let threshold = self.alpha * sigma;
+        coef.slice_mut(s![len..]).mapv_inplace(|c| st(threshold, c));
+
+        //inverse transform
+        for len in lengths.into_iter().rev() {
+            let half = len / 2;
+            let current = coef.slice(s![..len]).to_owned();
+            for i in 0..half {
+                coef[2 * i] = (current[i] + current[half + i]) / 2f64.sqrt();
+                coef[2 * i + 1] = (current[i] - current[half + i]) / 2f64.sqrt();
+            }
+        }
+        coef
+    }
+}
@@ -0,0 +1,160 @@
+//! # File IO
+//!
+//! 'file_io' reads and writes matrices and vectors in CSV, NumPy(.npy/.npz) and MatrixMarket format.
+mod mtx;
+mod npy;
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+pub use mtx::{
+    read_mtx_complex_matrix, read_mtx_coordinate, read_mtx_matrix, read_mtx_vector,
+    write_mtx_complex_matrix, write_mtx_coordinate, write_mtx_matrix, write_mtx_vector,
+    MtxCoordinate,
+};
+pub use npy::{
+    read_npy_complex_matrix, read_npy_complex_vector, read_npy_matrix, read_npy_vector,
+    write_npy_complex_matrix, write_npy_complex_vector, write_npy_matrix, write_npy_vector,
+    NpzReader, NpzWriter,
+};
+
+///Read a matrix from a CSV file without header(one row per line).
+pub fn read_csv_matrix(path: &str) -> Result<Array2<f64>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .trim(csv::Trim::All)
+        .from_path(path)?;
+
+    let mut values = Vec::new();
+    let mut row_num = 0;
+    let mut column_num = None;
+    for record in reader.records() {
+        let record = record?;
+        if *column_num.get_or_insert(record.len()) != record.len() {
+            return Err(anyhow!(format!(
+                "row {} of {} has {} columns, but row 0 has {}",
+                row_num,
+                path,
+                record.len(),
+                column_num.unwrap_or(0)
+            )));
+        }
+        for field in record.iter() {
+            values.push(
+                field
+                    .parse::<f64>()
+                    .map_err(|_| anyhow!(format!("can't parse {} in {} as f64", field, path)))?,
+            );
+        }
+        row_num += 1;
+    }
+
+    Ok(Array::from_shape_vec(
+        (row_num, column_num.unwrap_or(0)),
+        values,
+    )?)
+}
+
+///Read a vector from a CSV file. Both a single row and a single column are accepted.
+pub fn read_csv_vector(path: &str) -> Result<Array1<f64>> {
+    to_vector(read_csv_matrix(path)?)
+}
+
+///Write a matrix to a CSV file without header.
+pub fn write_csv_matrix(path: &str, mat: &Array2<f64>) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    for row in mat.rows() {
+        writer.write_record(row.iter().map(|v| v.to_string()))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+///Write a vector to a CSV file as a single column.
+pub fn write_csv_vector(path: &str, vec: &Array1<f64>) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    for v in vec.iter() {
+        writer.write_record([v.to_string()])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+///Read a matrix from .csv, .npy, .mtx or .npz file(judged by the extension).
+///An array in .npz file is specified as "path.npz:name".
+pub fn read_matrix(path: &str) -> Result<Array2<f64>> {
+    if let Some((path, name)) = split_npz_name(path) {
+        return NpzReader::open(path)?.matrix(name);
+    }
+    match extension(path).as_str() {
+        "csv" => read_csv_matrix(path),
+        "npy" => read_npy_matrix(path),
+        "mtx" => read_mtx_matrix(path),
+        "npz" => Err(npz_name_error(path)),
+        ext => Err(anyhow!(format!("unsupported extension: {}", ext))),
+    }
+}
+
+///Read a vector from .csv, .npy, .mtx or .npz file(judged by the extension).
+///An array in .npz file is specified as "path.npz:name".
+pub fn read_vector(path: &str) -> Result<Array1<f64>> {
+    if let Some((path, name)) = split_npz_name(path) {
+        return NpzReader::open(path)?.vector(name);
+    }
+    match extension(path).as_str() {
+        "csv" => read_csv_vector(path),
+        "npy" => read_npy_vector(path),
+        "mtx" => read_mtx_vector(path),
+        "npz" => Err(npz_name_error(path)),
+        ext => Err(anyhow!(format!("unsupported extension: {}", ext))),
+    }
+}
+
+///Write a vector to .csv, .npy, .mtx or .npz file(judged by the extension).
+///"path.npz:name" writes a .npz file which has only the vector named name.
+pub fn write_vector(path: &str, vec: &Array1<f64>) -> Result<()> {
+    if let Some((path, name)) = split_npz_name(path) {
+        let mut writer = NpzWriter::create(path)?;
+        writer.add_vector(name, vec)?;
+        return writer.finish();
+    }
+    match extension(path).as_str() {
+        "csv" => write_csv_vector(path, vec),
+        "npy" => write_npy_vector(path, vec),
+        "mtx" => write_mtx_vector(path, vec),
+        "npz" => Err(npz_name_error(path)),
+        ext => Err(anyhow!(format!("unsupported extension: {}", ext))),
+    }
+}
+
+//"path.npz:name" -> (path.npz, name)
+fn split_npz_name(path: &str) -> Option<(&str, &str)> {
+    path.rfind(".npz:")
+        .map(|i| (&path[..i + 4], &path[i + 5..]))
+}
+
+fn npz_name_error(path: &str) -> anyhow::Error {
+    anyhow!(format!(
+        "an array name is needed for .npz file, specify it as \"{}:name\"",
+        path
+    ))
+}
+
+fn extension(path: &str) -> String {
+    std::path::Path::new(path)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+fn to_vector(mat: Array2<f64>) -> Result<Array1<f64>> {
+    match mat.shape() {
+        [1, _] => Ok(mat.row(0).to_owned()),
+        [_, 1] => Ok(mat.column(0).to_owned()),
+        shape => Err(anyhow!(format!(
+            "{}x{} matrix can't be treated as a vector",
+            shape[0], shape[1]
+        ))),
+    }
+}
@@ -0,0 +1,318 @@
+//! MatrixMarket(.mtx) format
+use super::to_vector;
+use crate::prelude::*;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, PartialEq)]
+enum MtxFormat {
+    Array,
+    Coordinate,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum MtxField {
+    Real,
+    Integer,
+    Complex,
+    Pattern,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum MtxSymmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+//entries are 0-based, and symmetric parts are expanded
+struct MtxData {
+    shape: (usize, usize),
+    field: MtxField,
+    entries: Vec<(usize, usize, c64)>,
+}
+
+impl MtxData {
+    fn parse(text: &str) -> Result<Self> {
+        let mut lines = text.lines();
+
+        //banner: %%MatrixMarket matrix <format> <field> <symmetry>
+        let banner: Vec<String> = lines
+            .next()
+            .ok_or_else(|| anyhow!("mtx file is empty"))?
+            .split_whitespace()
+            .map(|v| v.to_lowercase())
+            .collect();
+        if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" {
+            return Err(anyhow!("mtx banner is broken"));
+        }
+        let format = match banner[2].as_str() {
+            "array" => MtxFormat::Array,
+            "coordinate" => MtxFormat::Coordinate,
+            v => return Err(anyhow!(format!("unsupported mtx format {}", v))),
+        };
+        let field = match banner[3].as_str() {
+            "real" | "double" => MtxField::Real,
+            "integer" => MtxField::Integer,
+            "complex" => MtxField::Complex,
+            "pattern" if format == MtxFormat::Coordinate => MtxField::Pattern,
+            v => return Err(anyhow!(format!("unsupported mtx field {}", v))),
+        };
+        let symmetry = match banner[4].as_str() {
+            "general" => MtxSymmetry::General,
+            "symmetric" => MtxSymmetry::Symmetric,
+            "skew-symmetric" => MtxSymmetry::SkewSymmetric,
+            "hermitian" => MtxSymmetry::Hermitian,
+            v => return Err(anyhow!(format!("unsupported mtx symmetry {}", v))),
+        };
+
+        let mut lines = lines
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('%'));
+        let parse_usize = |v: &str| {
+            v.parse::<usize>()
+                .map_err(|_| anyhow!(format!("can't parse {} as an index", v)))
+        };
+        let parse_f64 = |v: &str| {
+            v.parse::<f64>()
+                .map_err(|_| anyhow!(format!("can't parse {} as f64", v)))
+        };
+
+        let size: Vec<usize> = lines
+            .next()
+            .ok_or_else(|| anyhow!("mtx file doesn't have a size line"))?
+            .split_whitespace()
+            .map(parse_usize)
+            .collect::<Result<Vec<usize>>>()?;
+        let (shape, nnz) = match (format, size.as_slice()) {
+            (MtxFormat::Array, [rows, cols]) => ((*rows, *cols), None),
+            (MtxFormat::Coordinate, [rows, cols, nnz]) => ((*rows, *cols), Some(*nnz)),
+            _ => return Err(anyhow!("mtx size line is broken")),
+        };
+        if symmetry != MtxSymmetry::General && shape.0 != shape.1 {
+            return Err(anyhow!("symmetric mtx matrix is needed to be square"));
+        }
+
+        let value_len = match field {
+            MtxField::Complex => 2,
+            MtxField::Pattern => 0,
+            _ => 1,
+        };
+        let parse_value = |fields: &[&str]| -> Result<c64> {
+            match field {
+                MtxField::Complex => Ok(c64 {
+                    re: parse_f64(fields[0])?,
+                    im: parse_f64(fields[1])?,
+                }),
+                MtxField::Pattern => Ok(c64 { re: 1., im: 0. }),
+                _ => Ok(c64 {
+                    re: parse_f64(fields[0])?,
+                    im: 0.,
+                }),
+            }
+        };
+
+        let mut entries = Vec::new();
+        match format {
+            MtxFormat::Coordinate => {
+                for line in lines {
+                    let fields: Vec<&str> = line.split_whitespace().collect();
+                    if fields.len() != 2 + value_len {
+                        return Err(anyhow!(format!("mtx entry is broken: {}", line)));
+                    }
+                    let (i, j) = (parse_usize(fields[0])?, parse_usize(fields[1])?);
+                    if i == 0 || j == 0 || i > shape.0 || j > shape.1 {
+                        return Err(anyhow!(format!("mtx entry is out of range: {}", line)));
+                    }
+                    entries.push((i - 1, j - 1, parse_value(&fields[2..])?));
+                }
+                if Some(entries.len()) != nnz {
+                    return Err(anyhow!(format!(
+                        "mtx file has {} entries, but the size line says {}",
+                        entries.len(),
+                        nnz.unwrap_or(0)
+                    )));
+                }
+            }
+            MtxFormat::Array => {
+                //column major, and only the lower triangle if it is symmetric
+                let mut indexes = (0..shape.1)
+                    .flat_map(|j| (0..shape.0).map(move |i| (i, j)))
+                    .filter(|(i, j)| match symmetry {
+                        MtxSymmetry::General => true,
+                        MtxSymmetry::SkewSymmetric => i > j,
+                        _ => i >= j,
+                    });
+                for line in lines {
+                    let fields: Vec<&str> = line.split_whitespace().collect();
+                    if fields.len() != value_len {
+                        return Err(anyhow!(format!("mtx entry is broken: {}", line)));
+                    }
+                    let (i, j) = indexes
+                        .next()
+                        .ok_or_else(|| anyhow!("mtx file has too many entries"))?;
+                    entries.push((i, j, parse_value(&fields)?));
+                }
+                if indexes.next().is_some() {
+                    return Err(anyhow!("mtx file has too few entries"));
+                }
+            }
+        }
+
+        let expanded: Vec<(usize, usize, c64)> = entries
+            .iter()
+            .filter(|(i, j, _)| i != j)
+            .filter_map(|(i, j, v)| match symmetry {
+                MtxSymmetry::General => None,
+                MtxSymmetry::Symmetric => Some((*j, *i, *v)),
+                MtxSymmetry::SkewSymmetric => Some((*j, *i, -*v)),
+                MtxSymmetry::Hermitian => Some((*j, *i, v.conj())),
+            })
+            .collect();
+        entries.extend(expanded);
+
+        Ok(Self {
+            shape,
+            field,
+            entries,
+        })
+    }
+
+    fn read(path: &str) -> Result<Self> {
+        Self::parse(&fs::read_to_string(path)?).map_err(|e| anyhow!(format!("{}: {}", path, e)))
+    }
+
+    fn is_real(&self) -> Result<()> {
+        if self.field == MtxField::Complex {
+            return Err(anyhow!("mtx matrix is complex"));
+        }
+        Ok(())
+    }
+
+    fn into_coordinate(self) -> Result<MtxCoordinate> {
+        self.is_real()?;
+        Ok(MtxCoordinate {
+            shape: self.shape,
+            entries: self
+                .entries
+                .into_iter()
+                .map(|(i, j, v)| (i, j, v.re))
+                .collect(),
+        })
+    }
+
+    fn into_complex_matrix(self) -> Array2<c64> {
+        let mut mat = Array::zeros(self.shape);
+        for (i, j, v) in self.entries {
+            mat[[i, j]] += v;
+        }
+        mat
+    }
+}
+
+///Real matrix in coordinate format. entries are (row, column, value) and 0-based.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MtxCoordinate {
+    pub shape: (usize, usize),
+    pub entries: Vec<(usize, usize, f64)>,
+}
+
+impl MtxCoordinate {
+    ///Non-zero elements of mat.
+    pub fn from_dense(mat: &Array2<f64>) -> Self {
+        Self {
+            shape: (mat.nrows(), mat.ncols()),
+            entries: mat
+                .indexed_iter()
+                .filter(|(_, v)| **v != 0.)
+                .map(|((i, j), v)| (i, j, *v))
+                .collect(),
+        }
+    }
+
+    ///Duplicated entries are summed up.
+    pub fn to_dense(&self) -> Array2<f64> {
+        let mut mat = Array::zeros(self.shape);
+        for (i, j, v) in self.entries.iter() {
+            mat[[*i, *j]] += *v;
+        }
+        mat
+    }
+}
+
+///Read a real matrix in coordinate or array format as coordinate entries.
+pub fn read_mtx_coordinate(path: &str) -> Result<MtxCoordinate> {
+    MtxData::read(path)?.into_coordinate()
+}
+
+///Read a real matrix in coordinate or array format.
+pub fn read_mtx_matrix(path: &str) -> Result<Array2<f64>> {
+    Ok(read_mtx_coordinate(path)?.to_dense())
+}
+
+///Read a real vector(a matrix of a single row or column).
+pub fn read_mtx_vector(path: &str) -> Result<Array1<f64>> {
+    to_vector(read_mtx_matrix(path)?)
+}
+
+///Read a complex matrix. Real matrices are promoted.
+pub fn read_mtx_complex_matrix(path: &str) -> Result<Array2<c64>> {
+    Ok(MtxData::read(path)?.into_complex_matrix())
+}
+
+///Write a real matrix in array format.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use sparse_modeling::file_io::{read_mtx_matrix, write_mtx_matrix};
+///
+///let path = std::env::temp_dir().join("sparse_modeling_doc_mat.mtx");
+///let path = path.to_str().unwrap();
+///let mat = array![[0.1, 2.], [3., -4e-300], [0., 6.]];
+///write_mtx_matrix(path, &mat).unwrap();
+///assert_eq!(read_mtx_matrix(path).unwrap(), mat);
+/// ```
+pub fn write_mtx_matrix(path: &str, mat: &Array2<f64>) -> Result<()> {
+    let mut text = String::from("%%MatrixMarket matrix array real general\n");
+    writeln!(text, "{} {}", mat.nrows(), mat.ncols())?;
+    for v in mat.t().iter() {
+        writeln!(text, "{:e}", v)?;
+    }
+    fs::write(path, text)?;
+    Ok(())
+}
+
+///Write a real vector as a matrix of a single column.
+pub fn write_mtx_vector(path: &str, vec: &Array1<f64>) -> Result<()> {
+    write_mtx_matrix(path, &vec.clone().insert_axis(Axis(1)))
+}
+
+///Write a complex matrix(e.g. mk_dft_mat's output) in array format.
+pub fn write_mtx_complex_matrix(path: &str, mat: &Array2<c64>) -> Result<()> {
+    let mut text = String::from("%%MatrixMarket matrix array complex general\n");
+    writeln!(text, "{} {}", mat.nrows(), mat.ncols())?;
+    for v in mat.t().iter() {
+        writeln!(text, "{:e} {:e}", v.re, v.im)?;
+    }
+    fs::write(path, text)?;
+    Ok(())
+}
+
+///Write a real matrix in coordinate format.
+pub fn write_mtx_coordinate(path: &str, mat: &MtxCoordinate) -> Result<()> {
+    let mut text = String::from("%%MatrixMarket matrix coordinate real general\n");
+    writeln!(
+        text,
+        "{} {} {}",
+        mat.shape.0,
+        mat.shape.1,
+        mat.entries.len()
+    )?;
+    for (i, j, v) in mat.entries.iter() {
+        writeln!(text, "{} {} {:e}", i + 1, j + 1, v)?;
+    }
+    fs::write(path, text)?;
+    Ok(())
+}
@@ -0,0 +1,442 @@
+//! NumPy .npy and .npz format
+use super::to_vector;
+use crate::prelude::*;
+use std::io::{Read, Write};
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+struct NpyHeader {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+impl NpyHeader {
+    //parse "{'descr': '<f8', 'fortran_order': False, 'shape': (3, 4), }"
+    fn parse(header: &str) -> Result<Self> {
+        let value_of = |key: &str| -> Result<&str> {
+            let start = header
+                .find(&format!("'{}'", key))
+                .ok_or_else(|| anyhow!(format!("npy header doesn't have {}", key)))?
+                + key.len()
+                + 2;
+            let rest = header[start..].trim_start_matches([':', ' ']);
+            let end = if rest.starts_with('(') {
+                rest.find(')').map(|i| i + 1)
+            } else {
+                rest.find([',', '}'])
+            }
+            .ok_or_else(|| anyhow!(format!("npy header's {} is broken", key)))?;
+            Ok(rest[..end].trim())
+        };
+
+        let descr = value_of("descr")?.trim_matches(['\'', '"']).to_string();
+        let fortran_order = value_of("fortran_order")? == "True";
+        let shape = value_of("shape")?
+            .trim_matches(['(', ')'])
+            .split(',')
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .map(|v| {
+                v.parse::<usize>()
+                    .map_err(|_| anyhow!(format!("npy header's shape is broken: {}", v)))
+            })
+            .collect::<Result<Vec<usize>>>()?;
+
+        Ok(Self {
+            descr,
+            fortran_order,
+            shape,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let shape = match self.shape.len() {
+            1 => format!("({},)", self.shape[0]),
+            _ => format!(
+                "({})",
+                self.shape
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        };
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+            self.descr,
+            if self.fortran_order { "True" } else { "False" },
+            shape
+        );
+        //magic(6) + version(2) + header_len(2) + header is aligned to 64 bytes
+        let total_len = 10 + header.len() + 1;
+        header.push_str(&" ".repeat((64 - total_len % 64) % 64));
+        header.push('\n');
+
+        let mut bytes = NPY_MAGIC.to_vec();
+        bytes.extend([1u8, 0u8]);
+        bytes.extend((header.len() as u16).to_le_bytes());
+        bytes.extend(header.as_bytes());
+        bytes
+    }
+}
+
+//npy file whose data is not decoded yet
+struct NpyData {
+    header: NpyHeader,
+    data: Vec<u8>,
+}
+
+impl NpyData {
+    fn parse(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 10 || &bytes[..6] != NPY_MAGIC {
+            return Err(anyhow!("not a npy file"));
+        }
+        let (header_len, header_start) = match bytes[6] {
+            1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+            2 | 3 if bytes.len() >= 12 => (
+                u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+                12,
+            ),
+            version => return Err(anyhow!(format!("unsupported npy version {}", version))),
+        };
+        let data_start = header_start + header_len;
+        if bytes.len() < data_start {
+            return Err(anyhow!("npy header is broken"));
+        }
+        let header = NpyHeader::parse(&String::from_utf8_lossy(&bytes[header_start..data_start]))?;
+
+        Ok(Self {
+            header,
+            data: bytes[data_start..].to_vec(),
+        })
+    }
+
+    fn read(path: &str) -> Result<Self> {
+        let mut bytes = Vec::new();
+        fs::File::open(path)?.read_to_end(&mut bytes)?;
+        Self::parse(&bytes).map_err(|e| anyhow!(format!("{}: {}", path, e)))
+    }
+
+    fn len(&self) -> usize {
+        self.header.shape.iter().product()
+    }
+
+    fn chunks(&self, item_size: usize) -> Result<std::slice::ChunksExact<'_, u8>> {
+        if self.data.len() < self.len() * item_size {
+            return Err(anyhow!(format!(
+                "data size is {} bytes, {} bytes are needed",
+                self.data.len(),
+                self.len() * item_size
+            )));
+        }
+        Ok(self.data[..self.len() * item_size].chunks_exact(item_size))
+    }
+
+    //'<f8', '>f8', '<f4', '>f4', '<i8' and '<i4' are converted into f64
+    fn to_f64(&self) -> Result<Vec<f64>> {
+        let values = match self.header.descr.as_str() {
+            "<f8" => self
+                .chunks(8)?
+                .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+                .collect(),
+            ">f8" => self
+                .chunks(8)?
+                .map(|c| f64::from_be_bytes(c.try_into().unwrap()))
+                .collect(),
+            "<f4" => self
+                .chunks(4)?
+                .map(|c| f32::from_le_bytes(c.try_into().unwrap()) as f64)
+                .collect(),
+            ">f4" => self
+                .chunks(4)?
+                .map(|c| f32::from_be_bytes(c.try_into().unwrap()) as f64)
+                .collect(),
+            "<i8" => self
+                .chunks(8)?
+                .map(|c| i64::from_le_bytes(c.try_into().unwrap()) as f64)
+                .collect(),
+            "<i4" => self
+                .chunks(4)?
+                .map(|c| i32::from_le_bytes(c.try_into().unwrap()) as f64)
+                .collect(),
+            descr => return Err(anyhow!(format!("unsupported dtype {}", descr))),
+        };
+        Ok(values)
+    }
+
+    //'<c16', '>c16' and '<c8' are converted into c64, and real dtypes are promoted
+    fn to_c64(&self) -> Result<Vec<c64>> {
+        let values = match self.header.descr.as_str() {
+            "<c16" => self
+                .chunks(16)?
+                .map(|c| c64 {
+                    re: f64::from_le_bytes(c[..8].try_into().unwrap()),
+                    im: f64::from_le_bytes(c[8..].try_into().unwrap()),
+                })
+                .collect(),
+            ">c16" => self
+                .chunks(16)?
+                .map(|c| c64 {
+                    re: f64::from_be_bytes(c[..8].try_into().unwrap()),
+                    im: f64::from_be_bytes(c[8..].try_into().unwrap()),
+                })
+                .collect(),
+            "<c8" => self
+                .chunks(8)?
+                .map(|c| c64 {
+                    re: f32::from_le_bytes(c[..4].try_into().unwrap()) as f64,
+                    im: f32::from_le_bytes(c[4..].try_into().unwrap()) as f64,
+                })
+                .collect(),
+            _ => self
+                .to_f64()?
+                .into_iter()
+                .map(|re| c64 { re, im: 0. })
+                .collect(),
+        };
+        Ok(values)
+    }
+
+    fn shape_2d(&self) -> Result<(usize, usize)> {
+        if self.header.shape.len() != 2 {
+            return Err(anyhow!(format!(
+                "array is {}d, it is needed to be 2d",
+                self.header.shape.len()
+            )));
+        }
+        Ok((self.header.shape[0], self.header.shape[1]))
+    }
+
+    fn into_matrix(self) -> Result<Array2<f64>> {
+        let shape = self.shape_2d()?;
+        let values = self.to_f64()?;
+        if self.header.fortran_order {
+            Ok(Array::from_shape_vec(shape.f(), values)?)
+        } else {
+            Ok(Array::from_shape_vec(shape, values)?)
+        }
+    }
+
+    fn into_complex_matrix(self) -> Result<Array2<c64>> {
+        let shape = self.shape_2d()?;
+        let values = self.to_c64()?;
+        if self.header.fortran_order {
+            Ok(Array::from_shape_vec(shape.f(), values)?)
+        } else {
+            Ok(Array::from_shape_vec(shape, values)?)
+        }
+    }
+
+    //2d arrays of a single row or column are also accepted
+    fn into_vector(self) -> Result<Array1<f64>> {
+        match self.header.shape.len() {
+            1 => Ok(Array::from_vec(self.to_f64()?)),
+            _ => to_vector(self.into_matrix()?),
+        }
+    }
+
+    fn into_complex_vector(self) -> Result<Array1<c64>> {
+        match self.header.shape.len() {
+            1 => Ok(Array::from_vec(self.to_c64()?)),
+            _ => {
+                let mat = self.into_complex_matrix()?;
+                match mat.shape() {
+                    [1, _] => Ok(mat.row(0).to_owned()),
+                    [_, 1] => Ok(mat.column(0).to_owned()),
+                    shape => Err(anyhow!(format!(
+                        "{}x{} matrix can't be treated as a vector",
+                        shape[0], shape[1]
+                    ))),
+                }
+            }
+        }
+    }
+}
+
+fn npy_bytes_f64<'a, I: Iterator<Item = &'a f64>>(shape: &[usize], values: I) -> Vec<u8> {
+    let header = NpyHeader {
+        descr: "<f8".to_string(),
+        fortran_order: false,
+        shape: shape.to_vec(),
+    };
+    let mut bytes = header.to_bytes();
+    values.for_each(|v| bytes.extend(v.to_le_bytes()));
+    bytes
+}
+
+fn npy_bytes_c64<'a, I: Iterator<Item = &'a c64>>(shape: &[usize], values: I) -> Vec<u8> {
+    let header = NpyHeader {
+        descr: "<c16".to_string(),
+        fortran_order: false,
+        shape: shape.to_vec(),
+    };
+    let mut bytes = header.to_bytes();
+    values.for_each(|v| {
+        bytes.extend(v.re.to_le_bytes());
+        bytes.extend(v.im.to_le_bytes());
+    });
+    bytes
+}
+
+///Read a 2d array from a .npy file.
+///dtypes '<f8', '>f8', '<f4', '>f4', '<i8' and '<i4' are accepted and converted into f64.
+pub fn read_npy_matrix(path: &str) -> Result<Array2<f64>> {
+    NpyData::read(path)?.into_matrix()
+}
+
+///Read a 1d array from a .npy file. 2d arrays of a single row or column are also accepted.
+pub fn read_npy_vector(path: &str) -> Result<Array1<f64>> {
+    NpyData::read(path)?.into_vector()
+}
+
+///Read a complex 2d array('<c16', '>c16' or '<c8') from a .npy file. Real arrays are promoted.
+pub fn read_npy_complex_matrix(path: &str) -> Result<Array2<c64>> {
+    NpyData::read(path)?.into_complex_matrix()
+}
+
+///Read a complex 1d array from a .npy file. Real arrays are promoted.
+pub fn read_npy_complex_vector(path: &str) -> Result<Array1<c64>> {
+    NpyData::read(path)?.into_complex_vector()
+}
+
+///Write a 2d array to a .npy file('<f8', C order).
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use sparse_modeling::file_io::{read_npy_matrix, write_npy_matrix};
+///
+///let path = std::env::temp_dir().join("sparse_modeling_doc_mat.npy");
+///let path = path.to_str().unwrap();
+///let mat = array![[1., 2., 3.], [4., 5., 6.]];
+///write_npy_matrix(path, &mat).unwrap();
+///assert_eq!(read_npy_matrix(path).unwrap(), mat);
+/// ```
+pub fn write_npy_matrix(path: &str, mat: &Array2<f64>) -> Result<()> {
+    fs::File::create(path)?.write_all(&npy_bytes_f64(mat.shape(), mat.iter()))?;
+    Ok(())
+}
+
+///Write a 1d array to a .npy file('<f8').
+pub fn write_npy_vector(path: &str, vec: &Array1<f64>) -> Result<()> {
+    fs::File::create(path)?.write_all(&npy_bytes_f64(vec.shape(), vec.iter()))?;
+    Ok(())
+}
+
+///Write a complex 2d array(e.g. mk_dft_mat's output) to a .npy file('<c16', C order).
+pub fn write_npy_complex_matrix(path: &str, mat: &Array2<c64>) -> Result<()> {
+    fs::File::create(path)?.write_all(&npy_bytes_c64(mat.shape(), mat.iter()))?;
+    Ok(())
+}
+
+///Write a complex 1d array to a .npy file('<c16').
+pub fn write_npy_complex_vector(path: &str, vec: &Array1<c64>) -> Result<()> {
+    fs::File::create(path)?.write_all(&npy_bytes_c64(vec.shape(), vec.iter()))?;
+    Ok(())
+}
+
+///Reader of .npz files(numpy.savez / numpy.savez_compressed).
+pub struct NpzReader {
+    archive: zip::ZipArchive<fs::File>,
+}
+
+impl NpzReader {
+    pub fn open(path: &str) -> Result<Self> {
+        Ok(Self {
+            archive: zip::ZipArchive::new(fs::File::open(path)?)?,
+        })
+    }
+
+    ///Names of arrays in the archive.
+    pub fn names(&self) -> Vec<String> {
+        self.archive
+            .file_names()
+            .map(|name| name.trim_end_matches(".npy").to_string())
+            .collect()
+    }
+
+    fn data(&mut self, name: &str) -> Result<NpyData> {
+        let mut file = self
+            .archive
+            .by_name(&format!("{}.npy", name))
+            .map_err(|_| anyhow!(format!("npz doesn't have an array named {}", name)))?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        NpyData::parse(&bytes).map_err(|e| anyhow!(format!("{}: {}", name, e)))
+    }
+
+    pub fn matrix(&mut self, name: &str) -> Result<Array2<f64>> {
+        self.data(name)?.into_matrix()
+    }
+    pub fn vector(&mut self, name: &str) -> Result<Array1<f64>> {
+        self.data(name)?.into_vector()
+    }
+    pub fn complex_matrix(&mut self, name: &str) -> Result<Array2<c64>> {
+        self.data(name)?.into_complex_matrix()
+    }
+    pub fn complex_vector(&mut self, name: &str) -> Result<Array1<c64>> {
+        self.data(name)?.into_complex_vector()
+    }
+}
+
+///Writer of .npz files. Arrays are stored without compression like numpy.savez.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use sparse_modeling::file_io::{NpzReader, NpzWriter};
+///
+///let path = std::env::temp_dir().join("sparse_modeling_doc.npz");
+///let path = path.to_str().unwrap();
+///let mat = array![[1., 2.], [3., 4.], [5., 6.]];
+///let y = array![1., 0.5, 0.25];
+///let mut writer = NpzWriter::create(path).unwrap();
+///writer.add_matrix("A", &mat).unwrap();
+///writer.add_vector("y", &y).unwrap();
+///writer.finish().unwrap();
+///
+///let mut reader = NpzReader::open(path).unwrap();
+///assert_eq!(reader.matrix("A").unwrap(), mat);
+///assert_eq!(reader.vector("y").unwrap(), y);
+/// ```
+pub struct NpzWriter {
+    writer: zip::ZipWriter<fs::File>,
+}
+
+impl NpzWriter {
+    pub fn create(path: &str) -> Result<Self> {
+        Ok(Self {
+            writer: zip::ZipWriter::new(fs::File::create(path)?),
+        })
+    }
+
+    fn add(&mut self, name: &str, bytes: &[u8]) -> Result<()> {
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored)
+            .large_file(bytes.len() >= u32::MAX as usize);
+        self.writer.start_file(format!("{}.npy", name), options)?;
+        self.writer.write_all(bytes)?;
+        Ok(())
+    }
+
+    pub fn add_matrix(&mut self, name: &str, mat: &Array2<f64>) -> Result<()> {
+        self.add(name, &npy_bytes_f64(mat.shape(), mat.iter()))
+    }
+    pub fn add_vector(&mut self, name: &str, vec: &Array1<f64>) -> Result<()> {
+        self.add(name, &npy_bytes_f64(vec.shape(), vec.iter()))
+    }
+    pub fn add_complex_matrix(&mut self, name: &str, mat: &Array2<c64>) -> Result<()> {
+        self.add(name, &npy_bytes_c64(mat.shape(), mat.iter()))
+    }
+    pub fn add_complex_vector(&mut self, name: &str, vec: &Array1<c64>) -> Result<()> {
+        self.add(name, &npy_bytes_c64(vec.shape(), vec.iter()))
+    }
+
+    pub fn finish(self) -> Result<()> {
+        self.writer.finish()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+use super::*;
+
+fn temp_path(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!(
+            "sparse_modeling_file_io_{}_{}",
+            std::process::id(),
+            name
+        ))
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn read_mtx_text(name: &str, text: &str) -> Result<MtxCoordinate> {
+    let path = temp_path(name);
+    fs::write(&path, text)?;
+    let result = read_mtx_coordinate(&path);
+    fs::remove_file(&path)?;
+    result
+}
+
+fn npy_bytes(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+    let header = format!(
+        "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}\n",
+        descr,
+        if fortran_order { "True" } else { "False" },
+        shape
+    );
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend((header.len() as u16).to_le_bytes());
+    bytes.extend(header.as_bytes());
+    bytes.extend(data);
+    bytes
+}
+
+#[test]
+fn round_trip_test() {
+    let mat = array![[0.1, -2., 0.], [3., 4e-300, 1e300], [0., 6., -7.5]];
+    let vec = array![1., -0.5, 0., 2.5e-8];
+    for ext in ["csv", "npy", "mtx"] {
+        let mat_path = temp_path(&format!("mat.{}", ext));
+        let vec_path = temp_path(&format!("vec.{}", ext));
+        match ext {
+            "csv" => write_csv_matrix(&mat_path, &mat).unwrap(),
+            "npy" => write_npy_matrix(&mat_path, &mat).unwrap(),
+            _ => write_mtx_matrix(&mat_path, &mat).unwrap(),
+        }
+        write_vector(&vec_path, &vec).unwrap();
+        assert_eq!(read_matrix(&mat_path).unwrap(), mat, "{}", ext);
+        assert_eq!(read_vector(&vec_path).unwrap(), vec, "{}", ext);
+        fs::remove_file(&mat_path).unwrap();
+        fs::remove_file(&vec_path).unwrap();
+    }
+
+    let path = temp_path("vec.npz");
+    write_vector(&format!("{}:y", path), &vec).unwrap();
+    assert_eq!(read_vector(&format!("{}:y", path)).unwrap(), vec);
+    assert!(read_vector(&format!("{}:x", path)).is_err());
+    //a bare .npz path needs an array name
+    let err = read_vector(&path).unwrap_err().to_string();
+    assert!(err.contains("array name"), "{}", err);
+    assert!(read_matrix(&path).is_err());
+    assert!(write_vector(&path, &vec).is_err());
+    fs::remove_file(&path).unwrap();
+
+    let path = temp_path("coordinate.mtx");
+    let coordinate = MtxCoordinate::from_dense(&mat);
+    write_mtx_coordinate(&path, &coordinate).unwrap();
+    assert_eq!(read_mtx_coordinate(&path).unwrap(), coordinate);
+    assert_eq!(read_mtx_matrix(&path).unwrap(), mat);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn complex_test() {
+    let mat = array![
+        [c64::new(1., -1.), c64::new(0., 2.)],
+        [c64::new(-3.5, 0.), c64::new(4e-300, 5.)],
+        [c64::new(0., 0.), c64::new(6., -7.)]
+    ];
+    let vec = array![c64::new(1., 0.5), c64::new(-2., 0.)];
+
+    let path = temp_path("complex_mat.npy");
+    write_npy_complex_matrix(&path, &mat).unwrap();
+    assert_eq!(read_npy_complex_matrix(&path).unwrap(), mat);
+    assert!(read_npy_matrix(&path).is_err());
+    fs::remove_file(&path).unwrap();
+
+    let path = temp_path("complex_vec.npy");
+    write_npy_complex_vector(&path, &vec).unwrap();
+    assert_eq!(read_npy_complex_vector(&path).unwrap(), vec);
+    fs::remove_file(&path).unwrap();
+
+    let path = temp_path("complex.npz");
+    let mut writer = NpzWriter::create(&path).unwrap();
+    writer.add_complex_matrix("A", &mat).unwrap();
+    writer.add_complex_vector("y", &vec).unwrap();
+    writer.finish().unwrap();
+    let mut reader = NpzReader::open(&path).unwrap();
+    assert_eq!(reader.complex_matrix("A").unwrap(), mat);
+    assert_eq!(reader.complex_vector("y").unwrap(), vec);
+    fs::remove_file(&path).unwrap();
+
+    let path = temp_path("complex_mat.mtx");
+    write_mtx_complex_matrix(&path, &mat).unwrap();
+    assert_eq!(read_mtx_complex_matrix(&path).unwrap(), mat);
+    assert!(read_mtx_matrix(&path).is_err());
+    fs::remove_file(&path).unwrap();
+
+    //real arrays are promoted
+    let path = temp_path("real_mat.npy");
+    write_npy_matrix(&path, &array![[1., 2.]]).unwrap();
+    assert_eq!(
+        read_npy_complex_matrix(&path).unwrap(),
+        array![[c64::new(1., 0.), c64::new(2., 0.)]]
+    );
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn npy_dtype_test() {
+    let path = temp_path("dtype.npy");
+
+    //column major '>f8'
+    let data: Vec<u8> = [1., 3., 2., 4.]
+        .iter()
+        .flat_map(|v: &f64| v.to_be_bytes())
+        .collect();
+    fs::write(&path, npy_bytes(">f8", true, "(2, 2)", &data)).unwrap();
+    assert_eq!(read_npy_matrix(&path).unwrap(), array![[1., 2.], [3., 4.]]);
+
+    let data: Vec<u8> = [1i32, -2, 3].iter().flat_map(|v| v.to_le_bytes()).collect();
+    fs::write(&path, npy_bytes("<i4", false, "(3,)", &data)).unwrap();
+    assert_eq!(read_npy_vector(&path).unwrap(), array![1., -2., 3.]);
+
+    let data: Vec<u8> = [1f32, -2., 0.5, 0.]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    fs::write(&path, npy_bytes("<c8", false, "(2,)", &data)).unwrap();
+    assert_eq!(
+        read_npy_complex_vector(&path).unwrap(),
+        array![c64::new(1., -2.), c64::new(0.5, 0.)]
+    );
+
+    //2d arrays of a single column are vectors
+    let data: Vec<u8> = [1., 2.]
+        .iter()
+        .flat_map(|v: &f64| v.to_le_bytes())
+        .collect();
+    fs::write(&path, npy_bytes("<f8", false, "(2, 1)", &data)).unwrap();
+    assert_eq!(read_npy_vector(&path).unwrap(), array![1., 2.]);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn malformed_npy_test() {
+    let path = temp_path("malformed.npy");
+    let data: Vec<u8> = [1., 2., 3.]
+        .iter()
+        .flat_map(|v: &f64| v.to_le_bytes())
+        .collect();
+
+    fs::write(&path, npy_bytes("<f8", false, "(2, 2)", &data)).unwrap();
+    assert!(read_npy_matrix(&path).is_err());
+    fs::write(&path, npy_bytes("<u2", false, "(3,)", &data)).unwrap();
+    assert!(read_npy_vector(&path).is_err());
+    fs::write(&path, npy_bytes("<f8", false, "(3,)", &data)).unwrap();
+    assert!(read_npy_matrix(&path).is_err());
+    fs::write(&path, b"not a npy file").unwrap();
+    assert!(read_npy_vector(&path).is_err());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mtx_symmetry_test() {
+    let symmetric = read_mtx_text(
+        "symmetric.mtx",
+        "%%MatrixMarket matrix coordinate real symmetric\n%comment\n3 3 3\n1 1 1.0\n3 1 2.0\n3 2 -1\n",
+    )
+    .unwrap();
+    assert_eq!(
+        symmetric.to_dense(),
+        array![[1., 0., 2.], [0., 0., -1.], [2., -1., 0.]]
+    );
+
+    let skew = read_mtx_text(
+        "skew.mtx",
+        "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n2\n3\n",
+    )
+    .unwrap();
+    assert_eq!(
+        skew.to_dense(),
+        array![[0., -1., -2.], [1., 0., -3.], [2., 3., 0.]]
+    );
+
+    let symmetric_array = read_mtx_text(
+        "symmetric_array.mtx",
+        "%%MatrixMarket matrix array integer symmetric\n2 2\n1\n2\n3\n",
+    )
+    .unwrap();
+    assert_eq!(symmetric_array.to_dense(), array![[1., 2.], [2., 3.]]);
+
+    let pattern = read_mtx_text(
+        "pattern.mtx",
+        "%%MatrixMarket matrix coordinate pattern general\n2 3 2\n1 3\n2 1\n",
+    )
+    .unwrap();
+    assert_eq!(pattern.shape, (2, 3));
+    assert_eq!(pattern.entries, vec![(0, 2, 1.), (1, 0, 1.)]);
+
+    let path = temp_path("hermitian.mtx");
+    fs::write(
+        &path,
+        "%%MatrixMarket matrix coordinate complex hermitian\n2 2 2\n1 1 1 0\n2 1 2 3\n",
+    )
+    .unwrap();
+    assert_eq!(
+        read_mtx_complex_matrix(&path).unwrap(),
+        array![
+            [c64::new(1., 0.), c64::new(2., -3.)],
+            [c64::new(2., 3.), c64::new(0., 0.)]
+        ]
+    );
+    assert!(read_mtx_coordinate(&path).is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn malformed_mtx_test() {
+    let cases = [
+        //nnz on the size line doesn't match
+        "%%MatrixMarket matrix coordinate real general\n2 2 3\n1 1 1\n2 2 1\n",
+        "%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 1\n2 2 1\n",
+        //too few and too many values in array format
+        "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n",
+        "%%MatrixMarket matrix array real general\n1 2\n1\n2\n3\n",
+        "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n",
+        //out of range, broken entries and banners
+        "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n",
+        "%%MatrixMarket matrix coordinate real general\n2 2 1\n0 1 1\n",
+        "%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1\n",
+        "%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 x\n",
+        "%%MatrixMarket matrix coordinate real symmetric\n2 3 0\n",
+        "%%MatrixMarket matrix array pattern general\n1 1\n",
+        "%%MatrixMarket matrix array real general\n1 1 1\n1\n",
+        "%%MatrixMarket vector array real general\n1 1\n1\n",
+        "",
+    ];
+    for (i, text) in cases.iter().enumerate() {
+        assert!(read_mtx_text("malformed.mtx", text).is_err(), "case {}", i);
+    }
+}
+
+#[test]
+fn malformed_csv_test() {
+    let path = temp_path("malformed.csv");
+    fs::write(&path, "1, 2\n3\n").unwrap();
+    assert!(read_csv_matrix(&path).is_err());
+    fs::write(&path, "1, a\n").unwrap();
+    assert!(read_csv_matrix(&path).is_err());
+    fs::write(&path, "1, 2\n3, 4\n").unwrap();
+    assert!(read_csv_vector(&path).is_err());
+    assert!(read_matrix(&temp_path("matrix.txt")).is_err());
+    fs::remove_file(&path).unwrap();
+}
@@ -1,6 +1,9 @@
 //! # Gen Signal
 //! 
 //! 'gen_signal' is a colletion of tools for making random signals for test.
+#[cfg(test)]
+mod tests;
+
 use crate::prelude::*;
 
 ///Generate a 1d signal of specific length and pulses' locations and strength.
@@ -17,8 +20,8 @@ pub fn pulses_signal(size: usize, pulses: &[(usize, f64)]) -> Array1<f64> {
 
 ///Generate a 1d signal of specific length, number of pulse, the range of strength.
 #[allow(dead_code)]
-pub fn rand_pulses_signal(
-    rng: &mut ThreadRng,
+pub fn rand_pulses_signal<R: Rng + ?Sized>(
+    rng: &mut R,
     size: usize,
     pulse_num: usize,
     min_abs: f64,
@@ -50,3 +53,255 @@ pub fn rand_pulses_signal(
 
     Ok(signal)
 }
+
+///Generate a 1d signal of specific length and number of pulses, whose strengths follow N(0, std_dev^2).
+pub fn rand_gaussian_sparse_signal<R: Rng + ?Sized>(
+    rng: &mut R,
+    size: usize,
+    pulse_num: usize,
+    std_dev: f64,
+) -> Result<Array1<f64>> {
+    if pulse_num > size {
+        return Err(anyhow!(format!(
+            "pulse_num({}) is more than the size({})",
+            pulse_num, size
+        )));
+    }
+
+    let mut signal = Array::zeros(size);
+    for i in rand::seq::index::sample(rng, size, pulse_num) {
+        signal[i] = std_dev * rng.sample::<f64, _>(StandardNormal);
+    }
+
+    Ok(signal)
+}
+
+///Generate a block sparse 1d signal.
+///The signal is split into blocks of block_size and block_num blocks are filled with N(0, std_dev^2).
+///
+/// # Examples
+///
+/// ```
+/// use sparse_modeling::gen_signal::{rand_block_sparse_signal, SeededRng};
+///
+///let mut rng = SeededRng::new(0);
+///let x = rand_block_sparse_signal(&mut rng, 20, 4, 2, 1.0).unwrap();
+///assert_eq!(x.iter().filter(|v| **v != 0.).count(), 8);
+/// ```
+pub fn rand_block_sparse_signal<R: Rng + ?Sized>(
+    rng: &mut R,
+    size: usize,
+    block_size: usize,
+    block_num: usize,
+    std_dev: f64,
+) -> Result<Array1<f64>> {
+    if block_size == 0 || size % block_size != 0 {
+        return Err(anyhow!(format!(
+            "size({}) is not divisible by block_size({})",
+            size, block_size
+        )));
+    }
+    if block_num > size / block_size {
+        return Err(anyhow!(format!(
+            "block_num({}) is more than the number of blocks({})",
+            block_num,
+            size / block_size
+        )));
+    }
+
+    let mut signal = Array::zeros(size);
+    for block in rand::seq::index::sample(rng, size / block_size, block_num) {
+        for i in block * block_size..(block + 1) * block_size {
+            signal[i] = std_dev * rng.sample::<f64, _>(StandardNormal);
+        }
+    }
+
+    Ok(signal)
+}
+
+///Generate a compressible 1d signal.
+///The i-th largest magnitude is scale * i^(-decay), and locations and signs are random.
+pub fn rand_compressible_signal<R: Rng + ?Sized>(
+    rng: &mut R,
+    size: usize,
+    decay: f64,
+    scale: f64,
+) -> Result<Array1<f64>> {
+    if decay <= 0. {
+        return Err(anyhow!(format!(
+            "decay is {}, it is needed to be positive",
+            decay
+        )));
+    }
+
+    let mut signal = Array::zeros(size);
+    for (rank, i) in rand::seq::index::sample(rng, size, size)
+        .into_iter()
+        .enumerate()
+    {
+        signal[i] = scale * ((rank + 1) as f64).powf(-decay);
+        if rng.gen_bool(0.5) {
+            signal[i] *= -1.;
+        }
+    }
+
+    Ok(signal)
+}
+
+///Generate a signal which is sparse in dict(its columns are atoms).
+///return (coefficients, dict * coefficients)
+pub fn rand_dict_sparse_signal<R: Rng + ?Sized>(
+    rng: &mut R,
+    dict: &Array2<f64>,
+    pulse_num: usize,
+    min_abs: f64,
+    max_abs: f64,
+) -> Result<(Array1<f64>, Array1<f64>)> {
+    let coef = rand_pulses_signal(rng, dict.shape()[1], pulse_num, min_abs, max_abs)?;
+    let signal = dict.dot(&coef);
+    Ok((coef, signal))
+}
+
+///Generate a row sparse 2d signal(size x channel_num) for multiple measurement vectors.
+///row_num rows share the support, and their elements follow N(0, std_dev^2).
+pub fn rand_row_sparse_signal<R: Rng + ?Sized>(
+    rng: &mut R,
+    size: usize,
+    channel_num: usize,
+    row_num: usize,
+    std_dev: f64,
+) -> Result<Array2<f64>> {
+    if row_num > size {
+        return Err(anyhow!(format!(
+            "row_num({}) is more than the size({})",
+            row_num, size
+        )));
+    }
+
+    let mut signal = Array::zeros((size, channel_num));
+    for i in rand::seq::index::sample(rng, size, row_num) {
+        for j in 0..channel_num {
+            signal[[i, j]] = std_dev * rng.sample::<f64, _>(StandardNormal);
+        }
+    }
+
+    Ok(signal)
+}
+
+///Add white gaussian noise to mat * x so that the signal to noise ratio is snr_db[dB].
+pub fn noisy_measurement<R: Rng + ?Sized>(
+    rng: &mut R,
+    mat: &Array2<f64>,
+    x: &Array1<f64>,
+    snr_db: f64,
+) -> Result<Array1<f64>> {
+    if mat.shape()[1] != x.len() {
+        return Err(anyhow!(format!(
+            "mat's shape is {}x{} / x's size is {}",
+            mat.shape()[0],
+            mat.shape()[1],
+            x.len()
+        )));
+    }
+
+    let clean = mat.dot(x);
+    let signal_power = clean.norm_l2().powi(2) / clean.len() as f64;
+    let noise_std = (signal_power / 10f64.powf(snr_db / 10.)).sqrt();
+    let noise: Array1<f64> = Array::from_shape_fn(clean.len(), |_| {
+        noise_std * rng.sample::<f64, _>(StandardNormal)
+    });
+
+    Ok(clean + noise)
+}
+
+///Generate a test problem (A, x, y = Ax + noise).
+///A's elements follow N(0, 1/m), x has pulse_num gaussian pulses and y's SNR is snr_db[dB].
+pub fn rand_sparse_problem<R: Rng + ?Sized>(
+    rng: &mut R,
+    shape: (usize, usize),
+    pulse_num: usize,
+    snr_db: f64,
+) -> Result<(Array2<f64>, Array1<f64>, Array1<f64>)> {
+    let mat = rand_gaussian_mat(rng, shape, 1. / (shape.0 as f64).sqrt());
+    let x = rand_gaussian_sparse_signal(rng, shape.1, pulse_num, 1.)?;
+    let y = noisy_measurement(rng, &mat, &x, snr_db)?;
+    Ok((mat, x, y))
+}
+
+///Environment variable read by `SeededRng::from_env` to replay an experiment.
+pub const SEED_ENV_VAR: &str = "SPARSE_MODELING_SEED";
+
+///Rng for experiments and tests which remembers its seed.
+///
+/// # Examples
+///
+/// ```
+/// use rand::Rng;
+/// use sparse_modeling::gen_signal::SeededRng;
+///
+///let mut rng = SeededRng::new(42);
+///let mut replay = SeededRng::new(rng.seed());
+///assert_eq!(rng.gen::<f64>(), replay.gen::<f64>());
+/// ```
+pub struct SeededRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    ///Use the seed in SPARSE_MODELING_SEED if it is set, otherwise draw a new seed.
+    ///Callers can print seed() so that a failed run can be replayed.
+    ///
+    /// # Errors
+    /// if SPARSE_MODELING_SEED is not an u64 return Err.
+    pub fn from_env() -> Result<Self> {
+        let seed = match std::env::var(SEED_ENV_VAR) {
+            Ok(seed) => seed.trim().parse::<u64>().map_err(|_| {
+                anyhow!(format!(
+                    "{} is {}, it is needed to be u64",
+                    SEED_ENV_VAR, seed
+                ))
+            })?,
+            Err(_) => rand::thread_rng().gen(),
+        };
+        Ok(Self::new(seed))
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+///Rng for tests. The seed is fixed to 0 so that tests are deterministic,
+///and SPARSE_MODELING_SEED overrides it to run tests on other draws.
+#[cfg(test)]
+pub(crate) fn test_rng() -> SeededRng {
+    let rng = match std::env::var(SEED_ENV_VAR) {
+        Ok(_) => SeededRng::from_env().expect("can't create rng"),
+        Err(_) => SeededRng::new(0),
+    };
+    println!("{}={}", SEED_ENV_VAR, rng.seed());
+    rng
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> std::result::Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+fn nonzero_num(x: &Array1<f64>) -> usize {
+    x.iter().filter(|v| **v != 0.).count()
+}
+
+#[test]
+fn sparse_signal_test() {
+    let mut rng = SeededRng::new(0);
+    let x = rand_gaussian_sparse_signal(&mut rng, 100, 7, 2.).unwrap();
+    assert_eq!(x.len(), 100);
+    assert_eq!(nonzero_num(&x), 7);
+    assert!(rand_gaussian_sparse_signal(&mut rng, 5, 6, 1.).is_err());
+
+    let x = rand_block_sparse_signal(&mut rng, 60, 5, 3, 1.).unwrap();
+    assert_eq!(nonzero_num(&x), 15);
+    //nonzero elements fill whole blocks
+    for block in x.exact_chunks(5) {
+        let num = block.iter().filter(|v| **v != 0.).count();
+        assert!(num == 0 || num == 5);
+    }
+    assert!(rand_block_sparse_signal(&mut rng, 61, 5, 3, 1.).is_err());
+    assert!(rand_block_sparse_signal(&mut rng, 60, 0, 3, 1.).is_err());
+    assert!(rand_block_sparse_signal(&mut rng, 60, 5, 13, 1.).is_err());
+
+    let x = rand_compressible_signal(&mut rng, 50, 1.5, 3.).unwrap();
+    let mut magnitudes: Vec<f64> = x.iter().map(|v| v.abs()).collect();
+    magnitudes.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    for (rank, magnitude) in magnitudes.iter().enumerate() {
+        assert!((magnitude - 3. * ((rank + 1) as f64).powf(-1.5)).abs() < 1e-12);
+    }
+    assert!(rand_compressible_signal(&mut rng, 50, 0., 3.).is_err());
+
+    let dict = rand_gaussian_mat(&mut rng, (20, 40), 1.);
+    let (coef, signal) = rand_dict_sparse_signal(&mut rng, &dict, 4, 1., 2.).unwrap();
+    assert_eq!(nonzero_num(&coef), 4);
+    assert!(coef
+        .iter()
+        .all(|v| *v == 0. || (1. ..=2.).contains(&v.abs())));
+    assert!((&signal - &dict.dot(&coef)).norm_max() < 1e-12);
+}
+
+#[test]
+fn noisy_measurement_test() {
+    let mut rng = SeededRng::new(0);
+    let mat = rand_gaussian_mat(&mut rng, (20000, 10), 1.);
+    let x = rand_gaussian_sparse_signal(&mut rng, 10, 3, 1.).unwrap();
+    for snr_db in [0., 10., 30.] {
+        let y = noisy_measurement(&mut rng, &mat, &x, snr_db).unwrap();
+        let clean = mat.dot(&x);
+        let measured_snr_db =
+            10. * (clean.norm_l2().powi(2) / (&y - &clean).norm_l2().powi(2)).log10();
+        println!("snr: {}, measured: {}", snr_db, measured_snr_db);
+        assert!((measured_snr_db - snr_db).abs() < 0.2);
+    }
+    assert!(noisy_measurement(&mut rng, &mat, &Array::zeros(3), 10.).is_err());
+
+    let (mat, x, y) = rand_sparse_problem(&mut rng, (30, 80), 5, 20.).unwrap();
+    assert_eq!(mat.shape(), &[30, 80]);
+    assert_eq!(x.len(), 80);
+    assert_eq!(y.len(), 30);
+    assert_eq!(nonzero_num(&x), 5);
+    //columns of A have unit norm on average
+    let mean_norm = mat.column_norms().mean().unwrap();
+    assert!((mean_norm - 1.).abs() < 0.2);
+}
+
+#[test]
+fn seeded_rng_test() {
+    let mut rng1 = SeededRng::new(42);
+    let mut rng2 = SeededRng::new(42);
+    assert_eq!(rng1.seed(), 42);
+    assert_eq!(rng1.gen::<u64>(), rng2.gen::<u64>());
+}
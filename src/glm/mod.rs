@@ -0,0 +1,260 @@
+//! # GLM
+//!
+//! l1 regularized generalized linear models
+//! (minimize f(x) + λ||x||_(1), f is a smooth negative log likelihood given as a SmoothLoss).
+//! GlmLasso solves them by FISTA with backtracking of the Lipschitz constant(ProxGrad).
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+///log(1 + exp(t)) without overflow
+fn softplus(t: f64) -> f64 {
+    t.max(0.) + (-t.abs()).exp().ln_1p()
+}
+
+pub fn sigmoid(t: f64) -> f64 {
+    if t >= 0. {
+        1. / (1. + (-t).exp())
+    } else {
+        t.exp() / (1. + t.exp())
+    }
+}
+
+///Logistic regression loss Σ_i log(1 + exp(a_i^T x)) - y_i a_i^T x with labels y_i ∈ {0, 1}
+pub struct LogisticLoss {
+    mat: Array2<f64>,
+    labels: Array1<f64>,
+}
+
+impl LogisticLoss {
+    pub fn new(mat: &Array2<f64>, labels: &Array1<f64>) -> Result<Self> {
+        if mat.nrows() != labels.len() {
+            return Err(anyhow!(
+                "mat's row size and the number of labels are different"
+            ));
+        }
+        if labels.iter().any(|l| *l != 0. && *l != 1.) {
+            return Err(anyhow!("labels are needed to be 0 or 1"));
+        }
+        Ok(Self {
+            mat: mat.clone(),
+            labels: labels.clone(),
+        })
+    }
+    ///Probabilities of label 1 for rows
+    pub fn predict_proba(rows: &Array2<f64>, x: &Array1<f64>) -> Array1<f64> {
+        rows.dot(x).mapv(sigmoid)
+    }
+}
+
+impl SmoothLoss for LogisticLoss {
+    fn dim(&self) -> usize {
+        self.mat.ncols()
+    }
+    fn value(&self, x: &Array1<f64>) -> f64 {
+        self.mat
+            .dot(x)
+            .iter()
+            .zip(self.labels.iter())
+            .map(|(t, l)| softplus(*t) - l * t)
+            .sum()
+    }
+    fn gradient(&self, x: &Array1<f64>) -> Array1<f64> {
+        self.mat
+            .t()
+            .dot(&(self.mat.dot(x).mapv(sigmoid) - &self.labels))
+    }
+    fn lipshitz(&self) -> Option<f64> {
+        Some(0.25 * matrix_l2(&self.mat).powi(2))
+    }
+}
+
+///Poisson regression loss Σ_i exp(a_i^T x) - y_i a_i^T x with counts y_i >= 0
+pub struct PoissonLoss {
+    mat: Array2<f64>,
+    counts: Array1<f64>,
+}
+
+impl PoissonLoss {
+    pub fn new(mat: &Array2<f64>, counts: &Array1<f64>) -> Result<Self> {
+        if mat.nrows() != counts.len() {
+            return Err(anyhow!(
+                "mat's row size and the number of counts are different"
+            ));
+        }
+        if counts.iter().any(|c| *c < 0.) {
+            return Err(anyhow!("counts are needed to be non-negative"));
+        }
+        Ok(Self {
+            mat: mat.clone(),
+            counts: counts.clone(),
+        })
+    }
+}
+
+impl SmoothLoss for PoissonLoss {
+    fn dim(&self) -> usize {
+        self.mat.ncols()
+    }
+    fn value(&self, x: &Array1<f64>) -> f64 {
+        self.mat
+            .dot(x)
+            .iter()
+            .zip(self.counts.iter())
+            .map(|(t, c)| t.exp() - c * t)
+            .sum()
+    }
+    fn gradient(&self, x: &Array1<f64>) -> Array1<f64> {
+        self.mat
+            .t()
+            .dot(&(self.mat.dot(x).mapv(f64::exp) - &self.counts))
+    }
+}
+
+///Multinomial regression loss Σ_i logsumexp(a_i^T W) - a_i^T w_(y_i) with labels y_i ∈ {0, .., K - 1}.
+///x is W(p x K) flattened in row major order.
+pub struct MultinomialLoss {
+    mat: Array2<f64>,
+    labels: Vec<usize>,
+    class_num: usize,
+}
+
+impl MultinomialLoss {
+    pub fn new(mat: &Array2<f64>, labels: &[usize], class_num: usize) -> Result<Self> {
+        if mat.nrows() != labels.len() {
+            return Err(anyhow!(
+                "mat's row size and the number of labels are different"
+            ));
+        }
+        if labels.iter().any(|l| *l >= class_num) {
+            return Err(anyhow!(format!(
+                "labels are needed to be less than class_num({})",
+                class_num
+            )));
+        }
+        Ok(Self {
+            mat: mat.clone(),
+            labels: labels.to_vec(),
+            class_num,
+        })
+    }
+    ///W(p x K) from x
+    pub fn coef_matrix(&self, x: &Array1<f64>) -> Array2<f64> {
+        x.clone()
+            .into_shape((self.mat.ncols(), self.class_num))
+            .expect("x has a wrong size")
+    }
+    ///Class probabilities(rows x K) of rows
+    pub fn predict_proba(rows: &Array2<f64>, coef: &Array2<f64>) -> Array2<f64> {
+        let mut prob = rows.dot(coef);
+        for mut row in prob.rows_mut() {
+            let max = row.fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+            row.mapv_inplace(|v| (v - max).exp());
+            let sum = row.sum();
+            row /= sum;
+        }
+        prob
+    }
+}
+
+impl SmoothLoss for MultinomialLoss {
+    fn dim(&self) -> usize {
+        self.mat.ncols() * self.class_num
+    }
+    fn value(&self, x: &Array1<f64>) -> f64 {
+        let scores = self.mat.dot(&self.coef_matrix(x));
+        scores
+            .rows()
+            .into_iter()
+            .zip(self.labels.iter())
+            .map(|(row, l)| {
+                let max = row.fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+                max + row.mapv(|v| (v - max).exp()).sum().ln() - row[*l]
+            })
+            .sum()
+    }
+    fn gradient(&self, x: &Array1<f64>) -> Array1<f64> {
+        let mut residual = Self::predict_proba(&self.mat, &self.coef_matrix(x));
+        for (i, l) in self.labels.iter().enumerate() {
+            residual[[i, *l]] -= 1.;
+        }
+        Array::from_iter(self.mat.t().dot(&residual))
+    }
+    fn lipshitz(&self) -> Option<f64> {
+        Some(0.5 * matrix_l2(&self.mat).powi(2))
+    }
+}
+
+pub struct GlmLasso {
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl GlmLasso {
+    //rate of increasing the Lipschitz constant in backtracking
+    const BACKTRACK_RATE: f64 = 1.1;
+
+    #[allow(dead_code)]
+    pub fn new(iter_num: usize, threshold: f64) -> Self {
+        Self {
+            iter_num,
+            threshold,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, iter_num: usize, threshold: f64) {
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+    }
+
+    ///minimize loss(x) + λ||x||_1 and return the solution with the number of iterations
+    pub fn solve<L: SmoothLoss + ?Sized>(
+        &self,
+        loss: &L,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, usize)> {
+        let constraint = Constraint::Unconstrained;
+        let prox = L1Prox::new(lambda, &constraint);
+        let mut optimizer = ProxGrad::new(self.iter_num, self.threshold);
+        optimizer.set_acceleration(Acceleration::Fista);
+        optimizer.set_step_size(StepSize::Backtracking {
+            init: None,
+            rate: Self::BACKTRACK_RATE,
+        });
+        optimizer.minimize(loss, &prox, &Array::zeros(loss.dim()))
+    }
+
+    ///l1 regularized logistic regression with labels ∈ {0, 1}
+    pub fn logistic(
+        &self,
+        mat: &Array2<f64>,
+        labels: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<Array1<f64>> {
+        Ok(self.solve(&LogisticLoss::new(mat, labels)?, lambda)?.0)
+    }
+
+    ///l1 regularized Poisson regression with counts >= 0
+    pub fn poisson(
+        &self,
+        mat: &Array2<f64>,
+        counts: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<Array1<f64>> {
+        Ok(self.solve(&PoissonLoss::new(mat, counts)?, lambda)?.0)
+    }
+
+    ///l1 regularized multinomial regression. It returns the coefficient matrix W(p x K).
+    pub fn multinomial(
+        &self,
+        mat: &Array2<f64>,
+        labels: &[usize],
+        class_num: usize,
+        lambda: f64,
+    ) -> Result<Array2<f64>> {
+        let loss = MultinomialLoss::new(mat, labels, class_num)?;
+        let (x, _) = self.solve(&loss, lambda)?;
+        Ok(loss.coef_matrix(&x))
+    }
+}
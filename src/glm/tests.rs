@@ -0,0 +1,103 @@
+use super::*;
+
+//gradient is checked by central differences
+fn check_gradient<L: SmoothLoss>(loss: &L, x: &Array1<f64>) {
+    let grad = loss.gradient(x);
+    let eps = 1e-6;
+    for i in 0..x.len() {
+        let mut plus = x.clone();
+        plus[i] += eps;
+        let mut minus = x.clone();
+        minus[i] -= eps;
+        let numerical = (loss.value(&plus) - loss.value(&minus)) / (2. * eps);
+        assert!((numerical - grad[i]).abs() < 1e-4 * (1. + grad[i].abs()));
+    }
+}
+
+#[test]
+fn glm_gradient_test() {
+    let mut rng = test_rng();
+    let mat = rand_gaussian_mat(&mut rng, (20, 5), 1.);
+    let x: Array1<f64> = Array::from_shape_fn(5, |_| rng.gen_range(-0.5..0.5));
+    let labels: Array1<f64> = Array::from_shape_fn(20, |i| (i % 2) as f64);
+    check_gradient(&LogisticLoss::new(&mat, &labels).unwrap(), &x);
+    let counts: Array1<f64> = Array::from_shape_fn(20, |i| (i % 4) as f64);
+    check_gradient(&PoissonLoss::new(&mat, &counts).unwrap(), &x);
+    let classes: Vec<usize> = (0..20).map(|i| i % 3).collect();
+    let w: Array1<f64> = Array::from_shape_fn(15, |_| rng.gen_range(-0.5..0.5));
+    check_gradient(&MultinomialLoss::new(&mat, &classes, 3).unwrap(), &w);
+}
+
+#[test]
+fn sparse_logistic_test() {
+    let mut rng = test_rng();
+    let (sample_num, feature_num) = (400, 30);
+    let mat = rand_gaussian_mat(&mut rng, (sample_num, feature_num), 1.);
+    let mut coef: Array1<f64> = Array::zeros(feature_num);
+    coef[2] = 3.;
+    coef[11] = -3.;
+    coef[25] = 2.;
+    let prob = LogisticLoss::predict_proba(&mat, &coef);
+    let labels = prob.mapv(|p| if rng.gen::<f64>() < p { 1. } else { 0. });
+
+    let glm = GlmLasso::new(5000, 1e-10);
+    let result = glm.logistic(&mat, &labels, 10.).unwrap();
+    println!("logistic: {}", result);
+    assert_eq!(support(&result, 0.3), support(&coef, 0.3));
+    //signs are recovered
+    for i in [2, 11, 25] {
+        assert_eq!(result[i].signum(), coef[i].signum());
+    }
+}
+
+#[test]
+fn sparse_poisson_and_multinomial_test() {
+    let mut rng = test_rng();
+    let (sample_num, feature_num) = (400, 20);
+    let mat = rand_gaussian_mat(&mut rng, (sample_num, feature_num), 0.5);
+    let mut coef: Array1<f64> = Array::zeros(feature_num);
+    coef[4] = 1.;
+    coef[9] = -0.8;
+    //counts are the rounded means, which is enough for support recovery
+    let counts = mat.dot(&coef).mapv(|t| t.exp().round());
+    let glm = GlmLasso::new(5000, 1e-10);
+    let poisson_result = glm.poisson(&mat, &counts, 5.).unwrap();
+    println!("poisson: {}", poisson_result);
+    assert_eq!(support(&poisson_result, 0.2), support(&coef, 0.2));
+
+    //the class is the argmax of scores of 3 classes
+    let mut coef_mat: Array2<f64> = Array::zeros((feature_num, 3));
+    coef_mat[[1, 0]] = 3.;
+    coef_mat[[7, 1]] = 3.;
+    coef_mat[[15, 2]] = 3.;
+    let labels: Vec<usize> = mat
+        .dot(&coef_mat)
+        .rows()
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+                .unwrap()
+                .0
+        })
+        .collect();
+    let multinomial_result = glm.multinomial(&mat, &labels, 3, 2.).unwrap();
+    let prob = MultinomialLoss::predict_proba(&mat, &multinomial_result);
+    let accuracy = labels
+        .iter()
+        .enumerate()
+        .filter(|(i, l)| {
+            prob.row(*i)
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+                .unwrap()
+                .0
+                == **l
+        })
+        .count() as f64
+        / sample_num as f64;
+    println!("multinomial accuracy: {}", accuracy);
+    assert!(accuracy > 0.9);
+}
@@ -0,0 +1,105 @@
+//! # Constraint
+//!
+//! Constraint sets of proximal gradient solvers(minimize (1/2)||y - Ax||^(2) + λ||x||_(1) s.t. x ∈ C).
+//! Constraint::prox_l1 is the proximal operator of λ||x||_(1) + ι_C(x).
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Constraint {
+    #[default]
+    Unconstrained,
+    ///x >= 0
+    NonNegative,
+    ///lower <= x <= upper(element-wise)
+    Box {
+        lower: Array1<f64>,
+        upper: Array1<f64>,
+    },
+    ///x_i >= 0 if sign_i > 0, x_i <= 0 if sign_i < 0 and free if sign_i = 0
+    Sign(Array1<f64>),
+    ///x >= 0 and Σ_i x_i = radius
+    Simplex(f64),
+    ///||x||_2 <= radius
+    L2Ball(f64),
+}
+
+impl Constraint {
+    ///Check whether the constraint is valid for x of size n
+    pub fn check(&self, n: usize) -> Result<()> {
+        match self {
+            Constraint::Box { lower, upper } => {
+                if lower.len() != n || upper.len() != n {
+                    return Err(anyhow!(format!(
+                        "bounds have {} and {} elements, they are needed to be {}",
+                        lower.len(),
+                        upper.len(),
+                        n
+                    )));
+                }
+                if lower.iter().zip(upper.iter()).any(|(l, u)| l > u) {
+                    return Err(anyhow!("lower bounds are needed to be <= upper bounds"));
+                }
+            }
+            Constraint::Sign(sign) => {
+                if sign.len() != n {
+                    return Err(anyhow!(format!(
+                        "sign pattern has {} elements, it is needed to be {}",
+                        sign.len(),
+                        n
+                    )));
+                }
+            }
+            Constraint::Simplex(radius) | Constraint::L2Ball(radius) => {
+                if *radius < 0. {
+                    return Err(anyhow!(format!(
+                        "radius is {}, it is needed to be non-negative",
+                        radius
+                    )));
+                }
+            }
+            Constraint::Unconstrained | Constraint::NonNegative => (),
+        }
+        Ok(())
+    }
+
+    ///Euclidean projection onto the constraint set
+    pub fn project(&self, v: &Array1<f64>) -> Array1<f64> {
+        match self {
+            Constraint::Unconstrained => v.clone(),
+            Constraint::NonNegative => v.mapv(|v_i| v_i.max(0.)),
+            Constraint::Box { lower, upper } => {
+                Array::from_shape_fn(v.len(), |i| v[i].clamp(lower[i], upper[i]))
+            }
+            Constraint::Sign(sign) => Array::from_shape_fn(v.len(), |i| {
+                if sign[i] > 0. {
+                    v[i].max(0.)
+                } else if sign[i] < 0. {
+                    v[i].min(0.)
+                } else {
+                    v[i]
+                }
+            }),
+            Constraint::Simplex(radius) => project_simplex(v, *radius),
+            Constraint::L2Ball(radius) => {
+                let norm = v.norm_l2();
+                if norm <= *radius {
+                    v.clone()
+                } else {
+                    *radius / norm * v
+                }
+            }
+        }
+    }
+
+    ///Proximal operator of lambda||x||_1 + ι_C(x).
+    ///It is the projection after soft thresholding, except for the simplex
+    ///where ||x||_1 is constant and only the projection remains.
+    pub fn prox_l1(&self, lambda: f64, v: &Array1<f64>) -> Array1<f64> {
+        match self {
+            Constraint::Unconstrained => st_array1(lambda, v),
+            Constraint::NonNegative => nonneg_st_array1(lambda, v),
+            Constraint::Simplex(_) => self.project(v),
+            _ => self.project(&st_array1(lambda, v)),
+        }
+    }
+}
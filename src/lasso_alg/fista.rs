@@ -3,7 +3,11 @@ use crate::prelude::*;
 pub struct LassoFista {
     iter_num: usize,
     threshold: f64,
+    constraint: Constraint,
     lipshitz: Option<f64>,
+    backtracking: bool,
+    restart: Restart,
+    monotone: bool,
 }
 
 impl LassoFista {
@@ -12,7 +16,11 @@ impl LassoFista {
         Self {
             iter_num,
             threshold,
+            constraint: Constraint::Unconstrained,
             lipshitz: None,
+            backtracking: false,
+            restart: Restart::None,
+            monotone: false,
         }
     }
     #[allow(dead_code)]
@@ -20,6 +28,20 @@ impl LassoFista {
         self.iter_num = iter_num;
         self.threshold = threshold;
     }
+    ///Restrict solutions to be non-negative(projected soft thresholding)
+    #[allow(dead_code)]
+    pub fn set_non_negative(&mut self, non_negative: bool) {
+        self.constraint = if non_negative {
+            Constraint::NonNegative
+        } else {
+            Constraint::Unconstrained
+        };
+    }
+    ///Restrict solutions to the constraint set
+    #[allow(dead_code)]
+    pub fn set_constraint(&mut self, constraint: Constraint) {
+        self.constraint = constraint;
+    }
     #[allow(dead_code)]
     pub fn set_lipshitz(&mut self, lipshitz: f64) {
         self.lipshitz = Some(lipshitz)
@@ -28,46 +50,62 @@ impl LassoFista {
     pub fn lipshitz_to_none(&mut self) {
         self.lipshitz = None;
     }
+    ///Search the Lipschitz constant by backtracking(it starts from set_lipshitz's value or 1)
+    #[allow(dead_code)]
+    pub fn set_backtracking(&mut self, backtracking: bool) {
+        self.backtracking = backtracking;
+    }
+    ///Adaptive restart of the momentum
+    #[allow(dead_code)]
+    pub fn set_restart(&mut self, restart: Restart) {
+        self.restart = restart;
+    }
+    ///Use MFISTA, whose objective is non-increasing
+    #[allow(dead_code)]
+    pub fn set_monotone(&mut self, monotone: bool) {
+        self.monotone = monotone;
+    }
 }
 
 impl LassoAlg for LassoFista {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         //check data
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
         }
+        self.constraint.check(mat.shape().1)?;
 
-        //initialization
-        let mut x = mat.t().dot(y);
-        let mut prev_x;
-        let mut z = mat.t().dot(y);
-        let mut prev_z;
-        let lipshitz = if let Some(lip) = self.lipshitz {
-            lip
+        let loss = LeastSquaresLoss::new(mat, y)?;
+        let prox = L1Prox::new(lambda, &self.constraint);
+        let mut optimizer = ProxGrad::new(self.iter_num, self.threshold);
+        optimizer.set_acceleration(if self.monotone {
+            Acceleration::MonotoneFista
         } else {
-            let (_, mut s, _) = mat.svd(false, false).unwrap();
-            s.iter_mut().for_each(|v| *v = *v * *v);
-            s.norm_max() / lambda
-        };
-
-        let mut beta = 0.;
-        let mut prev_beta;
-
-        for _ in 0..self.iter_num {
-            prev_x = x.clone();
-            let v = &z + 1. / lipshitz / lambda * mat.t().dot(&(y - mat.dot(&x)));
-            x = st_array1(1. / lipshitz, &v);
-            prev_beta = beta;
-            beta = (1. + (1. + 4. * beta.powf(2.)).sqrt()) * 0.5;
-            prev_z = z.clone();
-            z = &x + (prev_beta - 1.) / beta * (&x - prev_x);
-
-            if (&z - prev_z).norm_l2() < self.threshold {
-                break;
+            Acceleration::Fista
+        });
+        //self.lipshitz is the one of (1/2λ)||y - Ax||^(2)
+        let lipshitz = self.lipshitz.map(|lip| lip * lambda);
+        optimizer.set_step_size(if self.backtracking {
+            StepSize::Backtracking {
+                init: Some(lipshitz.unwrap_or(1.)),
+                rate: 1.1,
             }
-        }
+        } else {
+            StepSize::Fixed(lipshitz)
+        });
+        optimizer.set_restart(self.restart);
+        let (x, iter) = optimizer.minimize(&loss, &prox, &mat.t_mul_vec(y))?;
 
-        Ok(z)
+        Ok((x, Some(iter)))
     }
 }
@@ -0,0 +1,75 @@
+//! # Fused Lasso
+//!
+//! FISTA(ProxGrad) for fused lasso
+//! (minimize (1/2)||y - Ax||^(2) + λ(||x||_(1) + tv_weight ||Dx||_(1)), D is the difference operator).
+//! The proximal operator is soft thresholding after exact 1-D TV denoising.
+use crate::prelude::*;
+use crate::tv::{tv_1d, tv_denoise_1d};
+
+///λ(||x||_(1) + tv_weight TV(x))
+struct FusedProx {
+    lambda: f64,
+    tv_weight: f64,
+}
+
+impl Prox for FusedProx {
+    fn value(&self, x: &Array1<f64>) -> f64 {
+        self.lambda * (x.norm_l1() + self.tv_weight * tv_1d(x))
+    }
+    fn prox(&self, step: f64, v: &Array1<f64>) -> Array1<f64> {
+        st_array1(
+            self.lambda * step,
+            &tv_denoise_1d(v, self.lambda * step * self.tv_weight),
+        )
+    }
+}
+
+pub struct LassoFused {
+    tv_weight: f64,
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl LassoFused {
+    #[allow(dead_code)]
+    pub fn new(tv_weight: f64, iter_num: usize, threshold: f64) -> Self {
+        Self {
+            tv_weight,
+            iter_num,
+            threshold,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, tv_weight: f64, iter_num: usize, threshold: f64) {
+        self.tv_weight = tv_weight;
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+    }
+}
+
+impl LassoAlg for LassoFused {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        //check data
+        is_underestimated_sys(mat, y)?;
+
+        let loss = LeastSquaresLoss::new(mat, y)?;
+        let prox = FusedProx {
+            lambda,
+            tv_weight: self.tv_weight,
+        };
+        let mut optimizer = ProxGrad::new(self.iter_num, self.threshold);
+        optimizer.set_acceleration(Acceleration::Fista);
+        let (x, iter) = optimizer.minimize(&loss, &prox, &mat.t_mul_vec(y))?;
+
+        Ok((x, Some(iter)))
+    }
+}
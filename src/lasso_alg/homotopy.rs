@@ -0,0 +1,184 @@
+//! # Lasso Homotopy
+//!
+//! Homotopy(LARS-lasso) algorithm tracing the exact piecewise linear solution path of
+//! minimize (1/2)||y - Ax||^(2) + λ||x||_(1) from λ_max = ||A^T y||_inf down to a target λ.
+//! At each breakpoint an element joins or leaves the support.
+use crate::prelude::*;
+
+///Breakpoints λ_0 > λ_1 > ... of the lasso path and the solutions at them.
+pub struct LassoPath {
+    pub lambdas: Vec<f64>,
+    pub coefs: Vec<Array1<f64>>,
+}
+
+impl LassoPath {
+    ///Solution at lambda by linear interpolation of the breakpoints.
+    ///Zeros for lambda >= λ_0 and the last solution for lambda below the last breakpoint.
+    pub fn solution_at(&self, lambda: f64) -> Array1<f64> {
+        let last = self.lambdas.len() - 1;
+        if lambda >= self.lambdas[0] {
+            return self.coefs[0].clone();
+        }
+        if lambda <= self.lambdas[last] {
+            return self.coefs[last].clone();
+        }
+        let k = self
+            .lambdas
+            .iter()
+            .position(|l| *l < lambda)
+            .expect("lambda is out of the path");
+        let ratio = (self.lambdas[k - 1] - lambda) / (self.lambdas[k - 1] - self.lambdas[k]);
+        &self.coefs[k - 1] + &(ratio * (&self.coefs[k] - &self.coefs[k - 1]))
+    }
+}
+
+pub struct LassoHomotopy {
+    iter_num: usize,
+    max_support: Option<usize>,
+}
+
+impl LassoHomotopy {
+    #[allow(dead_code)]
+    pub fn new(iter_num: usize) -> Self {
+        Self {
+            iter_num,
+            max_support: None,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, iter_num: usize) {
+        self.iter_num = iter_num;
+    }
+    ///Stop at the breakpoint where the support would exceed max_support(None: no limit),
+    ///so the last solution of the path has max_support nonzero elements.
+    #[allow(dead_code)]
+    pub fn set_max_support(&mut self, max_support: Option<usize>) {
+        self.max_support = max_support;
+    }
+
+    ///Trace the lasso path from λ_max down to min_lambda.
+    ///It also stops after iter_num breakpoints or when the support would exceed max_support.
+    pub fn path(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        min_lambda: f64,
+    ) -> Result<LassoPath> {
+        is_underestimated_sys(mat, y)?;
+        if min_lambda < 0. {
+            return Err(anyhow!(format!(
+                "min_lambda is {}, it is needed to be non-negative",
+                min_lambda
+            )));
+        }
+
+        //initialization
+        let mat = mat.to_dense();
+        let n = mat.ncols();
+        let mut x: Array1<f64> = Array::zeros(n);
+        let mut corr = mat.t().dot(y);
+        let (first, lambda_max) = corr
+            .iter()
+            .map(|c| c.abs())
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .expect("A has no columns");
+        let mut lambda = lambda_max;
+        let mut path = LassoPath {
+            lambdas: vec![lambda],
+            coefs: vec![x.clone()],
+        };
+        if lambda <= min_lambda {
+            return Ok(path);
+        }
+        let mut active = vec![first];
+
+        for _ in 0..self.iter_num {
+            //direction of x_S as λ decreases: (A_S^T A_S)^(-1) sign(c_S)
+            let mat_active = mat.select(Axis(1), &active);
+            let signs = Array::from_iter(active.iter().map(|i| corr[*i].signum()));
+            let direction = mat_active
+                .t()
+                .dot(&mat_active)
+                .inv()
+                .map_err(|_| anyhow!("columns of the support are linearly dependent"))?
+                .dot(&signs);
+            let v = mat.t().dot(&mat_active.dot(&direction));
+
+            //step to the next breakpoint
+            let mut step = lambda - min_lambda;
+            let mut event = None;
+            for j in (0..n).filter(|j| !active.contains(j)) {
+                for candidate in [
+                    (lambda - corr[j]) / (1. - v[j]),
+                    (lambda + corr[j]) / (1. + v[j]),
+                ] {
+                    if candidate > F64_EPS && candidate < step {
+                        step = candidate;
+                        event = Some((j, true));
+                    }
+                }
+            }
+            for (k, i) in active.iter().enumerate() {
+                let candidate = -x[*i] / direction[k];
+                if candidate > F64_EPS && candidate < step {
+                    step = candidate;
+                    event = Some((*i, false));
+                }
+            }
+
+            //update x, λ and correlations
+            for (k, i) in active.iter().enumerate() {
+                x[*i] += step * direction[k];
+            }
+            //λ reaches min_lambda exactly if no element joins or leaves
+            lambda = match event {
+                Some(_) => lambda - step,
+                None => min_lambda,
+            };
+            corr = mat.t().dot(&(y - &mat.dot(&x)));
+            match event {
+                Some((j, true)) => active.push(j),
+                Some((i, false)) => {
+                    x[i] = 0.;
+                    active.retain(|k| *k != i);
+                }
+                None => (),
+            }
+            path.lambdas.push(lambda);
+            path.coefs.push(x.clone());
+
+            if event.is_none()
+                || active.is_empty()
+                || self.max_support.is_some_and(|max| active.len() > max)
+            {
+                break;
+            }
+        }
+
+        Ok(path)
+    }
+}
+
+impl LassoAlg for LassoHomotopy {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        let path = self.path(mat, y, lambda)?;
+        let iter = path.lambdas.len() - 1;
+        if path.lambdas[iter] > lambda {
+            return Err(anyhow!(format!(
+                "the path stopped at λ = {} before reaching λ = {}(iter_num or max_support is exhausted)",
+                path.lambdas[iter], lambda
+            )));
+        }
+        Ok((path.solution_at(lambda), Some(iter)))
+    }
+}
@@ -24,7 +24,16 @@ impl LassoIrls {
 }
 
 impl LassoAlg for LassoIrls {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         //check data
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
@@ -32,18 +41,28 @@ impl LassoAlg for LassoIrls {
         }
 
         //initialization
-        let mut x: Array1<f64> = ArrayBase::ones(mat.shape()[1]);
+        let mut x: Array1<f64> = ArrayBase::ones(mat.shape().1);
         let mut prev_x;
         let mut weights = x.clone();
+        let squared_column_norms = mat.column_norms().mapv(|v| v * v);
 
+        let mut iter = 0;
         for _ in 0..self.iter_num {
+            iter += 1;
             //update x, weights
-            let mut temp = mat.t().dot(mat);
-            for i in 0..x.shape()[0] {
-                temp[[i, i]] += 2. * lambda / weights[i];
-            }
+            //solve (A^T A + diag(2λ/weights)) x = A^T y
+            let diag = weights.mapv(|w| 2. * lambda / w);
             prev_x = x;
-            x = conjugate_gradient(&temp, &mat.t().dot(y), 15, 0.).unwrap();
+            //Jacobi preconditioner diag(A^T A) + diag(2λ/weights)
+            let precond = JacobiPreconditioner::new(&(&squared_column_norms + &diag))?;
+            x = pcg_by(
+                |d| mat.t_mul_vec(&mat.mul_vec(d)) + &diag * d,
+                &precond,
+                &mat.t_mul_vec(y),
+                15,
+                0.,
+            )
+            .x;
             for i in 0..x.shape()[0] {
                 weights[i] = x[i].abs() + self.upsilon;
             }
@@ -53,6 +72,6 @@ impl LassoAlg for LassoIrls {
             }
         }
 
-        Ok(x)
+        Ok((x, Some(iter)))
     }
 }
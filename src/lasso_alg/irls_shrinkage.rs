@@ -18,7 +18,16 @@ impl LassoIrlsShrink {
 }
 
 impl LassoAlg for LassoIrlsShrink {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         //check data
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
@@ -26,26 +35,27 @@ impl LassoAlg for LassoIrlsShrink {
         }
 
         //initialization
-        let mut x: Array1<f64> = ArrayBase::ones(mat.shape()[1]);
+        let mut x: Array1<f64> = ArrayBase::ones(mat.shape().1);
         let mut prev_x;
-        let mut r = y - mat.dot(&x);
-        let (_, s, _) = mat.svd(false, false).unwrap();
-        let c = s.norm_max().powf(2.0) / 2. + 1.;
+        let mut r = y - mat.mul_vec(&x);
+        let c = matrix_l2(mat).powf(2.0) / 2. + 1.;
 
+        let mut iter = 0;
         for _ in 0..self.iter_num {
-            let e = mat.t().dot(&r);
+            iter += 1;
+            let e = mat.t_mul_vec(&r);
             prev_x = x.clone();
             for i in 0..x.shape()[0] {
                 let shrink = x[i].abs() / ( 2. * lambda / c +  x[i].abs() );
                 x[i] = shrink * (x[i] + e[i] / c);
             }
-            r = y - mat.dot(&x);
+            r = y - mat.mul_vec(&x);
 
             if (prev_x - &x).norm_l2() < self.threshold {
                 break;
             }
         }
 
-        Ok(x)
+        Ok((x, Some(iter)))
     }
 }
\ No newline at end of file
@@ -3,6 +3,7 @@ use crate::prelude::*;
 pub struct LassoIsta {
     iter_num: usize,
     threshold: f64,
+    constraint: Constraint,
 }
 
 impl LassoIsta {
@@ -11,6 +12,7 @@ impl LassoIsta {
         Self {
             iter_num,
             threshold,
+            constraint: Constraint::Unconstrained,
         }
     }
     #[allow(dead_code)]
@@ -18,30 +20,45 @@ impl LassoIsta {
         self.iter_num = iter_num;
         self.threshold = threshold;
     }
+    ///Restrict solutions to be non-negative(projected soft thresholding)
+    #[allow(dead_code)]
+    pub fn set_non_negative(&mut self, non_negative: bool) {
+        self.constraint = if non_negative {
+            Constraint::NonNegative
+        } else {
+            Constraint::Unconstrained
+        };
+    }
+    ///Restrict solutions to the constraint set
+    #[allow(dead_code)]
+    pub fn set_constraint(&mut self, constraint: Constraint) {
+        self.constraint = constraint;
+    }
 }
 
 impl LassoAlg for LassoIsta {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         //check data
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
         }
+        self.constraint.check(mat.shape().1)?;
 
-        //initialization
-        let mut x = mat.t().dot(y);
-        let mut prev_x;
-        let lipshitz = matrix_l2(&(mat.t().dot(mat))) / lambda;
-
-        for _ in 0..self.iter_num {
-            prev_x = x.clone();
-            let v = &x + 1. / lipshitz / lambda * mat.t().dot(&(y - mat.dot(&x)));
-            x = st_array1(1. / lipshitz, &v);
-            if (prev_x - x.clone()).norm_l2() < self.threshold {
-                break;
-            }
-        }
+        let loss = LeastSquaresLoss::new(mat, y)?;
+        let prox = L1Prox::new(lambda, &self.constraint);
+        let optimizer = ProxGrad::new(self.iter_num, self.threshold);
+        let (x, iter) = optimizer.minimize(&loss, &prox, &mat.t_mul_vec(y))?;
 
-        Ok(x)
+        Ok((x, Some(iter)))
     }
 }
@@ -2,25 +2,45 @@
 //! 
 //! 'lasso_alg' is a collection of tools for solve lasso
 //! (minimize (1/2λ)||y - Ax||^(2) + ||x||_(1) <- minimize).
+//! A is given as a LinearOperator, so dense and sparse(CSR/CSC) matrices are accepted.
 
+mod constraint;
 mod fista;
+mod fused;
+mod homotopy;
 mod irls;
 mod ista;
 mod ista_lipshitz_search;
 mod ssf;
 mod irls_shrinkage;
+mod pnp;
+mod robust;
 #[cfg(test)]
 mod tests;
 
 use crate::prelude::*;
 
+pub use constraint::Constraint;
 pub use fista::LassoFista;
+pub use fused::LassoFused;
+pub use homotopy::{LassoHomotopy, LassoPath};
 pub use irls::LassoIrls;
 pub use ista::LassoIsta;
 pub use ista_lipshitz_search::LassoIstaLipshitzSearch;
 pub use ssf::LassoSSF;
 pub use irls_shrinkage::LassoIrlsShrink;
+pub use pnp::{PnpAdmm, PnpProxGrad};
+pub use robust::{ExtendedLasso, LassoHuber, LassoLad};
 
 pub trait LassoAlg {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>>;
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>>;
+    ///Solve and return the solution with the number of iterations(None if it is not iterative).
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        Ok((self.solve(mat, y, lambda)?, None))
+    }
 }
@@ -0,0 +1,152 @@
+//! # Plug-and-Play
+//!
+//! Proximal gradient and ADMM whose proximal step(soft thresholding) is replaced by a Denoiser.
+//! With SoftThreshold::new(1.) they solve lasso.
+use crate::prelude::*;
+
+///Prox whose proximal step is denoise(v, λ step).
+///A denoiser has no explicit penalty, so value is 0 and it is used only without
+///Restart::Function and Acceleration::MonotoneFista.
+struct DenoiserProx<'a, D: Denoiser> {
+    denoiser: &'a D,
+    lambda: f64,
+}
+
+impl<D: Denoiser> Prox for DenoiserProx<'_, D> {
+    fn value(&self, _x: &Array1<f64>) -> f64 {
+        0.
+    }
+    fn prox(&self, step: f64, v: &Array1<f64>) -> Array1<f64> {
+        self.denoiser.denoise(v, self.lambda * step)
+    }
+}
+
+pub struct PnpProxGrad<D: Denoiser> {
+    denoiser: D,
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl<D: Denoiser> PnpProxGrad<D> {
+    #[allow(dead_code)]
+    pub fn new(denoiser: D, iter_num: usize, threshold: f64) -> Self {
+        Self {
+            denoiser,
+            iter_num,
+            threshold,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, iter_num: usize, threshold: f64) {
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+    }
+}
+
+impl<D: Denoiser> LassoAlg for PnpProxGrad<D> {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        //check data
+        is_underestimated_sys(mat, y)?;
+
+        let loss = LeastSquaresLoss::new(mat, y)?;
+        let prox = DenoiserProx {
+            denoiser: &self.denoiser,
+            lambda,
+        };
+        let optimizer = ProxGrad::new(self.iter_num, self.threshold);
+        let (x, iter) = optimizer.minimize(&loss, &prox, &mat.t_mul_vec(y))?;
+
+        Ok((x, Some(iter)))
+    }
+}
+
+pub struct PnpAdmm<D: Denoiser> {
+    denoiser: D,
+    rho: f64,
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl<D: Denoiser> PnpAdmm<D> {
+    #[allow(dead_code)]
+    pub fn new(denoiser: D, rho: f64, iter_num: usize, threshold: f64) -> Result<Self> {
+        if rho <= 0. {
+            return Err(anyhow!(format!(
+                "rho is {}, it is needed to be positive",
+                rho
+            )));
+        }
+        Ok(Self {
+            denoiser,
+            rho,
+            iter_num,
+            threshold,
+        })
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, rho: f64, iter_num: usize, threshold: f64) -> Result<()> {
+        if rho <= 0. {
+            return Err(anyhow!(format!(
+                "rho is {}, it is needed to be positive",
+                rho
+            )));
+        }
+        self.rho = rho;
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+        Ok(())
+    }
+}
+
+impl<D: Denoiser> LassoAlg for PnpAdmm<D> {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        //check data
+        is_underestimated_sys(mat, y)?;
+
+        //initialization
+        let n = mat.shape().1;
+        let mat_t_y = mat.t_mul_vec(y);
+        let mut z: Array1<f64> = Array::zeros(n);
+        let mut u: Array1<f64> = Array::zeros(n);
+        let mut prev_z;
+
+        let mut iter = 0;
+        for _ in 0..self.iter_num {
+            iter += 1;
+            prev_z = z.clone();
+            //solve (A^T A + ρI) x = A^T y + ρ(z - u)
+            let x = conjugate_gradient_by(
+                |d| mat.t_mul_vec(&mat.mul_vec(d)) + self.rho * d,
+                &(&mat_t_y + &(self.rho * (&z - &u))),
+                n,
+                F64_EPS,
+            );
+            z = self.denoiser.denoise(&(&x + &u), lambda / self.rho);
+            u = u + &x - &z;
+
+            if (&z - &prev_z).norm_l2() < self.threshold && (&x - &z).norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        Ok((z, Some(iter)))
+    }
+}
@@ -0,0 +1,228 @@
+//! # Robust Lasso
+//!
+//! Lasso variants robust to outliers of y.
+//! LassoHuber: minimize Σ_i h_δ((y - Ax)_i) + λ||x||_(1) (h_δ: Huber loss) by FISTA(ProxGrad).
+//! LassoLad: minimize ||y - Ax||_(1) + λ||x||_(1) by linear programming.
+//! ExtendedLasso: y = Ax + e with sparse outliers e,
+//! minimize (1/2)||y - Ax - e||^(2) + λ||x||_(1) + λ outlier_weight ||e||_(1) by FISTA(ProxGrad).
+use crate::lp::interior_point;
+use crate::prelude::*;
+
+pub struct LassoHuber {
+    delta: f64,
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl LassoHuber {
+    #[allow(dead_code)]
+    pub fn new(delta: f64, iter_num: usize, threshold: f64) -> Result<Self> {
+        if delta <= 0. {
+            return Err(anyhow!(format!(
+                "delta is {}, it is needed to be positive",
+                delta
+            )));
+        }
+        Ok(Self {
+            delta,
+            iter_num,
+            threshold,
+        })
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, delta: f64, iter_num: usize, threshold: f64) -> Result<()> {
+        if delta <= 0. {
+            return Err(anyhow!(format!(
+                "delta is {}, it is needed to be positive",
+                delta
+            )));
+        }
+        self.delta = delta;
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+        Ok(())
+    }
+}
+
+impl LassoAlg for LassoHuber {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        //check data
+        is_underestimated_sys(mat, y)?;
+
+        let loss = HuberLoss::new(mat, y, self.delta)?;
+        let constraint = Constraint::Unconstrained;
+        let prox = L1Prox::new(lambda, &constraint);
+        let mut optimizer = ProxGrad::new(self.iter_num, self.threshold);
+        optimizer.set_acceleration(Acceleration::Fista);
+        let (x, iter) = optimizer.minimize(&loss, &prox, &Array::zeros(mat.shape().1))?;
+
+        Ok((x, Some(iter)))
+    }
+}
+
+pub struct LassoLad {
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl LassoLad {
+    #[allow(dead_code)]
+    pub fn new(iter_num: usize, threshold: f64) -> Self {
+        Self {
+            iter_num,
+            threshold,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, iter_num: usize, threshold: f64) {
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+    }
+}
+
+impl LassoAlg for LassoLad {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        //check data
+        is_underestimated_sys(mat, y)?;
+
+        //x = u - v, y - Ax = p - q and minimize λ1^T(u + v) + 1^T(p + q)
+        //s.t. [A, -A, I, -I][u; v; p; q] = y, u, v, p, q >= 0
+        let (m, n) = mat.shape();
+        let mat = mat.to_dense();
+        let mut lp_mat: Array2<f64> = Array::zeros((m, 2 * n + 2 * m));
+        lp_mat.slice_mut(s![.., ..n]).assign(&mat);
+        lp_mat.slice_mut(s![.., n..2 * n]).assign(&(-&mat));
+        for i in 0..m {
+            lp_mat[[i, 2 * n + i]] = 1.;
+            lp_mat[[i, 2 * n + m + i]] = -1.;
+        }
+        let mut c: Array1<f64> = Array::ones(2 * n + 2 * m);
+        c.slice_mut(s![..2 * n]).fill(lambda);
+
+        let (solution, iter) = interior_point(&c, &lp_mat, y, self.iter_num, self.threshold)?;
+
+        Ok((
+            &solution.slice(s![..n]) - &solution.slice(s![n..2 * n]),
+            Some(iter),
+        ))
+    }
+}
+
+///(1/2)||y - [A, I]w||^(2) for w = [x; e]
+struct ExtendedLoss<'a> {
+    mat: &'a dyn LinearOperator,
+    y: &'a Array1<f64>,
+}
+
+impl ExtendedLoss<'_> {
+    fn residual(&self, w: &Array1<f64>) -> Array1<f64> {
+        let n = self.mat.shape().1;
+        self.y - &self.mat.mul_vec(&w.slice(s![..n]).to_owned()) - w.slice(s![n..])
+    }
+}
+
+impl SmoothLoss for ExtendedLoss<'_> {
+    fn dim(&self) -> usize {
+        self.mat.shape().1 + self.y.len()
+    }
+    fn value(&self, w: &Array1<f64>) -> f64 {
+        0.5 * self.residual(w).norm_l2().powi(2)
+    }
+    fn gradient(&self, w: &Array1<f64>) -> Array1<f64> {
+        let r = self.residual(w);
+        let mut grad = Array::zeros(self.dim());
+        let n = self.mat.shape().1;
+        grad.slice_mut(s![..n])
+            .assign(&(-1. * self.mat.t_mul_vec(&r)));
+        grad.slice_mut(s![n..]).assign(&(-1. * &r));
+        grad
+    }
+    ///||[A, I]||^2 <= ||A||^2 + 1
+    fn lipshitz(&self) -> Option<f64> {
+        Some(matrix_l2(self.mat).powi(2) + 1.)
+    }
+}
+
+pub struct ExtendedLasso {
+    outlier_weight: f64,
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl ExtendedLasso {
+    #[allow(dead_code)]
+    pub fn new(outlier_weight: f64, iter_num: usize, threshold: f64) -> Self {
+        Self {
+            outlier_weight,
+            iter_num,
+            threshold,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, outlier_weight: f64, iter_num: usize, threshold: f64) {
+        self.outlier_weight = outlier_weight;
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+    }
+
+    ///Solve and return x, the outlier vector e and the number of iterations
+    pub fn solve_extended(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Array1<f64>, usize)> {
+        //check data
+        is_underestimated_sys(mat, y)?;
+
+        //FISTA for w = [x; e] with the operator [A, I]
+        let (m, n) = mat.shape();
+        let loss = ExtendedLoss { mat, y };
+        let mut weights: Array1<f64> = Array::ones(n + m);
+        weights.slice_mut(s![n..]).fill(self.outlier_weight);
+        let prox = WeightedL1Prox::new(lambda, weights)?;
+        let mut optimizer = ProxGrad::new(self.iter_num, self.threshold);
+        optimizer.set_acceleration(Acceleration::Fista);
+        let (w, iter) = optimizer.minimize(&loss, &prox, &Array::zeros(n + m))?;
+
+        Ok((
+            w.slice(s![..n]).to_owned(),
+            w.slice(s![n..]).to_owned(),
+            iter,
+        ))
+    }
+}
+
+impl LassoAlg for ExtendedLasso {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_extended(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        let (x, _, iter) = self.solve_extended(mat, y, lambda)?;
+        Ok((x, Some(iter)))
+    }
+}
@@ -1,5 +1,5 @@
 //! # SSF
-//! 
+//!
 //! Separatable surrogate functional algorithm for LASSO
 use crate::prelude::*;
 
@@ -11,7 +11,10 @@ pub struct LassoSSF {
 impl LassoSSF {
     #[allow(dead_code)]
     pub fn new(iter_num: usize, threshold: f64) -> Self {
-        Self { iter_num, threshold }
+        Self {
+            iter_num,
+            threshold,
+        }
     }
     #[allow(dead_code)]
     pub fn set(&mut self, iter_num: usize, threshold: f64) {
@@ -21,31 +24,29 @@ impl LassoSSF {
 }
 
 impl LassoAlg for LassoSSF {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+    fn solve(&self, mat: &dyn LinearOperator, y: &Array1<f64>, lambda: f64) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y, lambda)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+        lambda: f64,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         //check data
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
         }
 
-        //initialization
-        let mut x: Array1<f64> = ArrayBase::zeros(mat.shape()[1]);
-        let mut prev_x;
-        let mut r = y.clone();
-        let (_, s, _) = mat.svd(false, false).unwrap();
-        let c = s.norm_max().powf(2.0);
-
-        for _ in 0..self.iter_num {
-            let e = mat.t().dot(&r);
-            prev_x = x;
-            x = st_array1(lambda / c, &(&prev_x + e / c));
-            r = y - mat.dot(&x);
+        //SSF is ISTA started from 0
+        let loss = LeastSquaresLoss::new(mat, y)?;
+        let constraint = Constraint::Unconstrained;
+        let prox = L1Prox::new(lambda, &constraint);
+        let optimizer = ProxGrad::new(self.iter_num, self.threshold);
+        let (x, iter) = optimizer.minimize(&loss, &prox, &Array::zeros(mat.shape().1))?;
 
-            if (prev_x - &x).norm_l2() < self.threshold {
-                break;
-            }
-        }
-
-        Ok(x)
+        Ok((x, Some(iter)))
     }
-}
\ No newline at end of file
+}
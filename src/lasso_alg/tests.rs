@@ -4,10 +4,10 @@ use super::*;
 
 #[test]
 fn lasso_1sample_test() {
-    let mut rng = rand::thread_rng();
+    let mut rng = test_rng();
     let input_data: Array1<f64> =
         rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate a signal");
-    let matrix: Array2<f64> = ArrayBase::from_shape_fn((30, 50), |_| rng.gen_range(-1.0..1.0));
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
     let output_data = matrix.dot(&input_data);
 
     let lambda = 1e-2;
@@ -102,3 +102,293 @@ fn lasso_1sample_test() {
     );
     chart.draw_series(point_series).unwrap();
 }
+
+#[test]
+fn pnp_test() {
+    let mut rng = test_rng();
+    let input_data: Array1<f64> =
+        rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate a signal");
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    let output_data = matrix.dot(&input_data);
+
+    let lambda = 1e-2;
+    let iter_num = 500;
+    let threshold = 1e-20;
+    let ista_result = LassoIsta::new(iter_num, threshold)
+        .solve(&matrix, &output_data, lambda)
+        .expect("can't solve lasso's ista");
+    let fista_result = LassoFista::new(5000, threshold)
+        .solve(&matrix, &output_data, lambda)
+        .expect("can't solve fista");
+
+    //soft thresholding as the denoiser gives lasso solutions
+    let pnp_prox_grad = PnpProxGrad::new(SoftThreshold::new(1.), iter_num, threshold);
+    let prox_grad_result = pnp_prox_grad
+        .solve(&matrix, &output_data, lambda)
+        .expect("can't solve pnp proximal gradient");
+    assert!((&prox_grad_result - &ista_result).norm_l2() < 1e-8);
+
+    let pnp_admm = PnpAdmm::new(SoftThreshold::new(1.), 1., 5000, 1e-12).unwrap();
+    let admm_result = pnp_admm
+        .solve(&matrix, &output_data, lambda)
+        .expect("can't solve pnp admm");
+    println!("admm - fista: {}", (&admm_result - &fista_result).norm_l2());
+    assert!((&admm_result - &fista_result).norm_l2() < 1e-4);
+}
+
+#[test]
+fn fused_lasso_test() {
+    let mut rng = test_rng();
+    //step profile
+    let input_data: Array1<f64> = Array::from_shape_fn(60, |i| match i {
+        10..=24 => 1.5,
+        40..=49 => -1.,
+        _ => 0.,
+    });
+    let matrix: Array2<f64> = rand_gaussian_mat(&mut rng, (40, 60), 1. / 40f64.sqrt());
+    let output_data = matrix.dot(&input_data);
+
+    let lambda = 1e-3;
+    let fused = LassoFused::new(10., 20000, 1e-12);
+    let fused_result = fused
+        .solve(&matrix, &output_data, lambda)
+        .expect("can't solve fused lasso");
+    let fista_result = LassoFista::new(20000, 1e-12)
+        .solve(&matrix, &output_data, lambda)
+        .expect("can't solve fista");
+
+    let fused_err = l2_relative_err(&input_data, &fused_result).unwrap();
+    let fista_err = l2_relative_err(&input_data, &fista_result).unwrap();
+    println!("fused: {}, lasso: {}", fused_err, fista_err);
+    assert!(fused_err < 0.05);
+    assert!(fused_err < fista_err);
+}
+
+#[test]
+fn homotopy_test() {
+    let mut rng = test_rng();
+    let input_data: Array1<f64> =
+        rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate a signal");
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    let output_data = noisy_measurement(&mut rng, &matrix, &input_data, 30.).unwrap();
+
+    let homotopy = LassoHomotopy::new(1000);
+    let path = homotopy.path(&matrix, &output_data, 1e-2).unwrap();
+    assert!(path.lambdas.windows(2).all(|w| w[0] > w[1]));
+    assert_eq!(*path.lambdas.last().unwrap(), 1e-2);
+    assert_eq!(path.coefs[0], Array1::<f64>::zeros(50));
+
+    //the exact path is the reference of iterative solvers
+    for lambda in [1e-2, 0.1, 1.] {
+        let homotopy_result = path.solution_at(lambda);
+        let fista_result = LassoFista::new(100000, 1e-14)
+            .solve(&matrix, &output_data, lambda)
+            .expect("can't solve fista");
+        println!(
+            "lambda: {}, homotopy - fista: {}",
+            lambda,
+            (&homotopy_result - &fista_result).norm_l2()
+        );
+        assert!((&homotopy_result - &fista_result).norm_l2() < 1e-6);
+    }
+
+    //stop at a target sparsity
+    let mut sparse_homotopy = LassoHomotopy::new(1000);
+    sparse_homotopy.set_max_support(Some(3));
+    let sparse_path = sparse_homotopy.path(&matrix, &output_data, 0.).unwrap();
+    let last = sparse_path.coefs.last().unwrap();
+    assert_eq!(last.iter().filter(|v| **v != 0.).count(), 3);
+
+    //solve fails if the path stops before the target λ
+    assert!(sparse_homotopy.solve(&matrix, &output_data, 0.).is_err());
+    assert!(LassoHomotopy::new(1)
+        .solve(&matrix, &output_data, 1e-2)
+        .is_err());
+    let (homotopy_result, _) = homotopy
+        .solve_with_iter(&matrix, &output_data, 1e-2)
+        .unwrap();
+    assert_eq!(homotopy_result, path.solution_at(1e-2));
+}
+
+#[test]
+fn nn_lasso_test() {
+    let mut rng = test_rng();
+    let input_data: Array1<f64> = rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0)
+        .expect("can't generate a signal")
+        .mapv(f64::abs);
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    let output_data = noisy_measurement(&mut rng, &matrix, &input_data, 20.).unwrap();
+
+    let lambda = 0.1;
+    let mut ista = LassoIsta::new(10000, 1e-12);
+    ista.set_non_negative(true);
+    let mut fista = LassoFista::new(10000, 1e-12);
+    fista.set_non_negative(true);
+    let ista_result = ista.solve(&matrix, &output_data, lambda).unwrap();
+    let fista_result = fista.solve(&matrix, &output_data, lambda).unwrap();
+
+    assert!(ista_result.iter().all(|v| *v >= 0.));
+    assert!(fista_result.iter().all(|v| *v >= 0.));
+    assert!((&ista_result - &fista_result).norm_l2() < 1e-4);
+    assert_eq!(support(&fista_result, 0.1), support(&input_data, 0.1));
+}
+
+#[test]
+fn constrained_lasso_test() {
+    //with A = I, the solution is the combined proximal operator of y
+    let y = array![2., -1.5, 0.3, -0.05, 1.];
+    let matrix: Array2<f64> = Array::eye(5);
+    let lambda = 0.1;
+    let cases = vec![
+        (
+            Constraint::Box {
+                lower: Array::from_elem(5, -1.),
+                upper: Array::from_elem(5, 1.5),
+            },
+            array![1.5, -1., 0.2, 0., 0.9],
+        ),
+        (
+            Constraint::Sign(array![1., 1., -1., 0., 0.]),
+            array![1.9, 0., 0., 0., 0.9],
+        ),
+        (Constraint::Simplex(1.), array![1., 0., 0., 0., 0.]),
+        (
+            Constraint::L2Ball(1.),
+            array![1.9, -1.4, 0.2, 0., 0.9] / 2.5337718918639855,
+        ),
+    ];
+    for (constraint, expected) in cases {
+        let mut ista = LassoIsta::new(1000, 1e-14);
+        ista.set_constraint(constraint.clone());
+        let mut fista = LassoFista::new(1000, 1e-14);
+        fista.set_constraint(constraint.clone());
+        let mut ista_ls = LassoIstaLipshitzSearch::new(1000, 1e-14);
+        ista_ls.set_constraint(constraint.clone());
+        for result in [
+            ista.solve(&matrix, &y, lambda).unwrap(),
+            fista.solve(&matrix, &y, lambda).unwrap(),
+            ista_ls.solve(&matrix, &y, lambda).unwrap(),
+        ] {
+            println!("{:?}: {}", constraint, result);
+            assert!((&result - &expected).norm_l2() < 1e-8);
+        }
+    }
+
+    //box constrained solutions of a random problem are feasible and agree with each other
+    let mut rng = test_rng();
+    let input_data: Array1<f64> =
+        rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate a signal");
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    let output_data = matrix.dot(&input_data);
+    let constraint = Constraint::Box {
+        lower: Array::from_elem(50, -0.5),
+        upper: Array::from_elem(50, 0.5),
+    };
+    let mut ista = LassoIsta::new(100000, 1e-14);
+    ista.set_constraint(constraint.clone());
+    let mut fista = LassoFista::new(100000, 1e-14);
+    fista.set_constraint(constraint);
+    let ista_result = ista.solve(&matrix, &output_data, lambda).unwrap();
+    let fista_result = fista.solve(&matrix, &output_data, lambda).unwrap();
+    assert!(fista_result.iter().all(|v| v.abs() <= 0.5));
+    assert!((&ista_result - &fista_result).norm_l2() < 1e-5);
+
+    let mut invalid = LassoIsta::new(10, 1e-14);
+    invalid.set_constraint(Constraint::Sign(array![1.]));
+    assert!(invalid.solve(&matrix, &output_data, lambda).is_err());
+}
+
+#[test]
+fn robust_lasso_test() {
+    let mut rng = test_rng();
+    let input_data: Array1<f64> =
+        rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate a signal");
+    let matrix: Array2<f64> = rand_gaussian_mat(&mut rng, (40, 50), 1. / 40f64.sqrt());
+    //gross outliers at 3 measurements
+    let mut outliers: Array1<f64> = Array::zeros(40);
+    for (i, v) in [(3, 10.), (17, -8.), (31, 12.)] {
+        outliers[i] = v;
+    }
+    let output_data = matrix.dot(&input_data) + &outliers;
+
+    let lambda = 1e-2;
+    let fista_result = LassoFista::new(10000, 1e-12)
+        .solve(&matrix, &output_data, lambda)
+        .unwrap();
+    let huber_result = LassoHuber::new(0.1, 10000, 1e-12)
+        .unwrap()
+        .solve(&matrix, &output_data, lambda)
+        .unwrap();
+    let lad_result = LassoLad::new(100, 1e-10)
+        .solve(&matrix, &output_data, lambda)
+        .unwrap();
+    let extended = ExtendedLasso::new(1., 10000, 1e-12);
+    let (extended_result, detected, _) = extended
+        .solve_extended(&matrix, &output_data, lambda)
+        .unwrap();
+
+    let fista_err = l2_relative_err(&input_data, &fista_result).unwrap();
+    println!("l2_relative_err|| lasso: {}", fista_err);
+    for (name, result) in [
+        ("huber", &huber_result),
+        ("lad", &lad_result),
+        ("extended", &extended_result),
+    ] {
+        let err = l2_relative_err(&input_data, result).unwrap();
+        println!("l2_relative_err|| {}: {}", name, err);
+        assert!(err < 0.1);
+        assert!(err < fista_err);
+    }
+    assert_eq!(support(&detected, 1.), support(&outliers, 1.));
+}
+
+#[test]
+fn fista_options_test() {
+    //the iterations are compared on a fixed problem
+    let mut rng = SeededRng::new(0);
+    let input_data: Array1<f64> =
+        rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate a signal");
+    //ill-conditioned matrix whose column norms decay geometrically
+    let mut matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    for (j, mut column) in matrix.columns_mut().into_iter().enumerate() {
+        column *= 0.9f64.powi(j as i32);
+    }
+    let output_data = matrix.dot(&input_data);
+    let lambda = 1e-2;
+    let exact = LassoHomotopy::new(1000)
+        .path(&matrix, &output_data, lambda)
+        .unwrap()
+        .solution_at(lambda);
+
+    let mut iters = vec![];
+    for (backtracking, restart, monotone) in [
+        (false, Restart::None, false),
+        (true, Restart::None, false),
+        (false, Restart::Function, false),
+        (false, Restart::Gradient, false),
+        (true, Restart::Gradient, false),
+        (false, Restart::None, true),
+        (true, Restart::Gradient, true),
+    ] {
+        let mut fista = LassoFista::new(200000, 1e-14);
+        fista.set_backtracking(backtracking);
+        fista.set_restart(restart);
+        fista.set_monotone(monotone);
+        let (result, iter) = fista
+            .solve_with_iter(&matrix, &output_data, lambda)
+            .expect("can't solve fista");
+        println!(
+            "backtracking: {}, restart: {:?}, monotone: {}, iter: {:?}, err: {}",
+            backtracking,
+            restart,
+            monotone,
+            iter,
+            (&result - &exact).norm_l2()
+        );
+        assert!((&result - &exact).norm_l2() < 1e-6);
+        iters.push(iter.unwrap());
+    }
+    //restart suppresses oscillation
+    assert!(iters[2] < iters[0]);
+    assert!(iters[3] < iters[0]);
+}
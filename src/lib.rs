@@ -1,33 +1,50 @@
 //! # Sparse Modeling
 //! 
 //! 'sparse_modeling' is a collection of utilities to calculate sparse solutions.
+pub mod analysis_alg;
+pub mod benchmark;
 pub mod cg;
+pub mod denoiser;
+pub mod file_io;
 pub mod gen_signal;
+pub mod glm;
 pub mod lasso_alg;
+pub mod lp;
 pub mod math_func;
 pub mod mk_matrix;
+pub mod mmv_alg;
+pub mod optim;
+pub mod phase_transition;
 pub mod sparse_alg;
+pub mod sparse_mat;
+pub mod tv;
 mod prelude {
     //! # Prelude
     //! functions, structures, and so on, used throughout this crate
+    pub use crate::analysis_alg::*;
     pub use crate::gen_signal::*;
     pub use crate::lasso_alg::*;
     pub use crate::math_func::*;
     pub use crate::mk_matrix::*;
+    pub use crate::mmv_alg::*;
+    pub use crate::optim::*;
     pub use crate::sparse_alg::*;
+    pub use crate::sparse_mat::*;
     pub use anyhow::{anyhow, Result};
     pub use ndarray::prelude::*;
     pub use ndarray_linalg::{c64, generate, svd::SVD, Inverse, Norm, Scalar};
     pub use plotters::prelude::*;
     pub use rand::{
         distributions::{Distribution, Uniform},
-        rngs::ThreadRng,
-        Rng,
+        rngs::StdRng,
+        Rng, RngCore, SeedableRng,
     };
+    pub use rand_distr::StandardNormal;
     pub use std::cmp;
     pub use std::collections::HashSet;
     pub use std::{f64::consts::PI, fs};
     pub const F64_EPS: f64 = 1e-10;
 
     pub use crate::cg::*;
+    pub use crate::denoiser::*;
 }
\ No newline at end of file
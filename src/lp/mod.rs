@@ -0,0 +1,99 @@
+//! # LP
+//!
+//! Linear programming in the standard form
+//! (minimize c^T x s.t. Ax = b, x >= 0)
+//! by Mehrotra's predictor-corrector primal-dual interior point method.
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+use ndarray_linalg::{FactorizeInto, Solve};
+
+///Solve the LP and return the solution with the number of iterations.
+///It stops when the relative primal/dual residuals and the duality measure are less than threshold,
+///and returns Err if they aren't within iter_num iterations.
+pub fn interior_point(
+    c: &Array1<f64>,
+    a: &Array2<f64>,
+    b: &Array1<f64>,
+    iter_num: usize,
+    threshold: f64,
+) -> Result<(Array1<f64>, usize)> {
+    let (m, n) = (a.nrows(), a.ncols());
+    if c.len() != n || b.len() != m {
+        return Err(anyhow!(format!(
+            "shapes of c({}), A({:?}) and b({}) are not consistent",
+            c.len(),
+            a.shape(),
+            b.len()
+        )));
+    }
+
+    //initialization
+    let mut x: Array1<f64> = Array::ones(n);
+    let mut s: Array1<f64> = Array::ones(n);
+    let mut lambda: Array1<f64> = Array::zeros(m);
+    let (b_scale, c_scale) = (1. + b.norm_l2(), 1. + c.norm_l2());
+
+    let mut iter = 0;
+    loop {
+        let r_p = b - &a.dot(&x);
+        let r_d = c - &a.t().dot(&lambda) - &s;
+        let mu = x.dot(&s) / n as f64;
+        let (primal_err, dual_err) = (r_p.norm_l2() / b_scale, r_d.norm_l2() / c_scale);
+        if primal_err < threshold && dual_err < threshold && mu < threshold {
+            break;
+        }
+        if iter == iter_num {
+            return Err(anyhow!(format!(
+                "interior point method didn't converge in {} iterations(primal residual: {:e}, dual residual: {:e}, duality measure: {:e})",
+                iter_num, primal_err, dual_err, mu
+            )));
+        }
+        iter += 1;
+
+        //normal equations A D A^T, D = X S^(-1)
+        let d = &x / &s;
+        let normal = (a * &d.view().insert_axis(Axis(0)))
+            .dot(&a.t())
+            .factorize_into()
+            .map_err(|_| anyhow!("can't factorize the normal equations of the LP"))?;
+        //solve A dx = r_p, A^T dlambda + ds = r_d, S dx + X ds = r_c
+        let newton_step = |r_c: &Array1<f64>| -> Result<_> {
+            let d_lambda = normal
+                .solve(&(&r_p + &a.dot(&(&d * &r_d - &(r_c / &s)))))
+                .map_err(|_| anyhow!("can't solve the normal equations of the LP"))?;
+            let d_s = &r_d - &a.t().dot(&d_lambda);
+            let d_x = (r_c - &(&x * &d_s)) / &s;
+            Ok((d_x, d_lambda, d_s))
+        };
+
+        //predictor(affine scaling) step
+        let (dx_aff, _, ds_aff) = newton_step(&(-&x * &s))?;
+        let alpha_p_aff = max_step(&x, &dx_aff).min(1.);
+        let alpha_d_aff = max_step(&s, &ds_aff).min(1.);
+        let mu_aff =
+            (&x + &(alpha_p_aff * &dx_aff)).dot(&(&s + &(alpha_d_aff * &ds_aff))) / n as f64;
+        let sigma = (mu_aff / mu).powi(3);
+
+        //corrector step
+        let r_c = sigma * mu - &x * &s - &dx_aff * &ds_aff;
+        let (dx, d_lambda, ds) = newton_step(&r_c)?;
+        let alpha_p = (0.99 * max_step(&x, &dx)).min(1.);
+        let alpha_d = (0.99 * max_step(&s, &ds)).min(1.);
+        x = x + alpha_p * dx;
+        lambda = lambda + alpha_d * d_lambda;
+        s = s + alpha_d * ds;
+    }
+
+    Ok((x, iter))
+}
+
+///The largest α such that v + α dv >= 0
+fn max_step(v: &Array1<f64>, dv: &Array1<f64>) -> f64 {
+    v.iter()
+        .zip(dv.iter())
+        .filter(|(_, dv_i)| **dv_i < 0.)
+        .map(|(v_i, dv_i)| -v_i / dv_i)
+        .fold(f64::INFINITY, f64::min)
+}
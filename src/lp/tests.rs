@@ -0,0 +1,16 @@
+use super::*;
+
+#[test]
+fn interior_point_test() {
+    //minimize -x_1 - x_2 s.t. x_1 + 2x_2 <= 4, 3x_1 + x_2 <= 6(with slack variables)
+    let c = array![-1., -1., 0., 0.];
+    let a = array![[1., 2., 1., 0.], [3., 1., 0., 1.]];
+    let b = array![4., 6.];
+    let (x, iter) = interior_point(&c, &a, &b, 100, 1e-10).unwrap();
+    println!("x: {}, iter: {}", x, iter);
+    assert!((x.slice(s![..2]).to_owned() - array![1.6, 1.2]).norm_l2() < 1e-6);
+    assert!(x.iter().all(|v| *v >= 0.));
+
+    assert!(interior_point(&c, &a, &array![4.], 100, 1e-10).is_err());
+    assert!(interior_point(&c, &a, &b, 2, 1e-10).is_err());
+}
@@ -23,10 +23,283 @@ pub fn st_array1(lambda: f64, x: &Array1<f64>) -> Array1<f64> {
     y
 }
 
-///Operator l2 norm for Array2<f64>.
-pub fn matrix_l2(mat: &Array2<f64>) -> f64 {
-    let (_, s, _) = mat.svd(false, false).unwrap();
-    s.norm_max()
+///Non-negative soft thresholding function for Array1<f64>(max(x - lambda, 0)).
+pub fn nonneg_st_array1(lambda: f64, x: &Array1<f64>) -> Array1<f64> {
+    x.mapv(|v| (v - lambda).max(0.))
+}
+
+///Row-wise group soft thresholding function for Array2<f64>.
+///Each row x_i is shrunk to max(0, 1 - lambda / ||x_i||_2) x_i.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+///
+///let x = array![[3., 4.], [0.3, 0.4]];
+///assert_eq!(sparse_modeling::math_func::row_st_array2(1., &x), array![[2.4, 3.2], [0., 0.]]);
+/// ```
+pub fn row_st_array2(lambda: f64, x: &Array2<f64>) -> Array2<f64> {
+    let mut y = x.clone();
+    y.rows_mut().into_iter().for_each(|mut row| {
+        let l2_norm = row.norm_l2();
+        let shrink = if l2_norm <= lambda {
+            0.
+        } else {
+            1. - lambda / l2_norm
+        };
+        row.mapv_inplace(|v| v * shrink);
+    });
+    y
+}
+
+///Euclidean projection onto the l1 ball {x | ||x||_1 <= tau}.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+///
+///let x = array![3., -1., 0.5];
+///assert_eq!(sparse_modeling::math_func::project_l1_ball(&x, 2.), array![2., 0., 0.]);
+/// ```
+pub fn project_l1_ball(x: &Array1<f64>, tau: f64) -> Array1<f64> {
+    if x.norm_l1() <= tau {
+        return x.clone();
+    }
+    if tau <= 0. {
+        return Array::zeros(x.len());
+    }
+
+    //threshold θ = (Σ_(i<=k) u_i - tau) / k for the largest k such that u_k > θ(u: sorted |x|)
+    let mut abs_x: Vec<f64> = x.iter().map(|v| v.abs()).collect();
+    abs_x.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    let mut cumsum = 0.;
+    let mut theta = 0.;
+    for (k, u) in abs_x.iter().enumerate() {
+        cumsum += u;
+        let candidate = (cumsum - tau) / (k + 1) as f64;
+        if *u <= candidate {
+            break;
+        }
+        theta = candidate;
+    }
+    st_array1(theta, x)
+}
+
+///Euclidean projection onto the simplex {x | x >= 0, Σ_i x_i = radius}.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+///
+///let x = array![2., 0.5, -1.];
+///assert_eq!(sparse_modeling::math_func::project_simplex(&x, 1.), array![1., 0., 0.]);
+/// ```
+pub fn project_simplex(x: &Array1<f64>, radius: f64) -> Array1<f64> {
+    //threshold θ = (Σ_(i<=k) u_i - radius) / k for the largest k such that u_k > θ(u: sorted x)
+    let mut sorted: Vec<f64> = x.to_vec();
+    sorted.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    let mut cumsum = 0.;
+    let mut theta = 0.;
+    for (k, u) in sorted.iter().enumerate() {
+        cumsum += u;
+        let candidate = (cumsum - radius) / (k + 1) as f64;
+        if k > 0 && *u <= candidate {
+            break;
+        }
+        theta = candidate;
+    }
+    x.mapv(|v| (v - theta).max(0.))
+}
+
+//relative tolerance and maximum iterations of matrix_l2
+const MATRIX_L2_TOLERANCE: f64 = 1e-12;
+const MATRIX_L2_ITER_NUM: usize = 300;
+
+///Operator l2 norm for Array2<f64>, CsrMatrix, CscMatrix and other LinearOperators.
+///It is estimated by lanczos_l2 without a full SVD.
+pub fn matrix_l2<M: LinearOperator + ?Sized>(mat: &M) -> f64 {
+    lanczos_l2(mat, MATRIX_L2_TOLERANCE, MATRIX_L2_ITER_NUM)
+}
+
+//deterministic starting vector of spectral norm estimators
+fn spectral_start_vec(n: usize) -> Array1<f64> {
+    let mut rng = StdRng::seed_from_u64(0);
+    let v: Array1<f64> = Array::from_shape_fn(n, |_| rng.sample(StandardNormal));
+    &v / v.norm_l2()
+}
+
+///Operator l2 norm by power iteration on A^T A.
+///It stops when the relative change of the estimate is less than tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+///
+///let mat = array![[3., 0.], [0., 1.], [0., 0.]];
+///let norm = sparse_modeling::math_func::power_iteration_l2(&mat, 1e-12, 1000);
+///assert!((norm - 3.).abs() < 1e-10);
+/// ```
+pub fn power_iteration_l2<M: LinearOperator + ?Sized>(
+    mat: &M,
+    tolerance: f64,
+    iter_num: usize,
+) -> f64 {
+    let mut v = spectral_start_vec(mat.shape().1);
+    let mut sigma = 0.;
+    for _ in 0..iter_num {
+        let w = mat.t_mul_vec(&mat.mul_vec(&v));
+        let w_norm = w.norm_l2();
+        if w_norm == 0. {
+            return 0.;
+        }
+        let prev_sigma = sigma;
+        sigma = w_norm.sqrt();
+        v = w / w_norm;
+        if (sigma - prev_sigma).abs() <= tolerance * sigma {
+            break;
+        }
+    }
+    sigma
+}
+
+///Operator l2 norm by Lanczos(Golub-Kahan) bidiagonalization with full reorthogonalization.
+///The estimate is the Ritz value σ^2 of A^T A(the largest eigenvalue of the tridiagonal B^T B, A V = U B)
+///plus its residual bound, so it stops when the residual bound is less than tolerance * σ^2.
+///It is exact after min(row size, column size) + 1 iterations.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+///
+///let mat = array![[1., 2.], [3., 4.], [5., 6.]];
+///let norm = sparse_modeling::math_func::lanczos_l2(&mat, 1e-12, 100);
+///assert!((norm - 9.525518091565107).abs() < 1e-10);
+/// ```
+pub fn lanczos_l2<M: LinearOperator + ?Sized>(mat: &M, tolerance: f64, iter_num: usize) -> f64 {
+    let (row_size, column_size) = mat.shape();
+    let mut vs = vec![spectral_start_vec(column_size)];
+    let mut us: Vec<Array1<f64>> = vec![];
+    //B^T B = tridiag(off, diag, off)
+    let mut diag = vec![];
+    let mut off = vec![];
+    let (mut prev_alpha, mut prev_beta) = (0., 0.);
+    let mut ritz_value = 0.;
+    let mut residual = 0.;
+
+    let mut p = mat.mul_vec(&vs[0]);
+    for k in 0..iter_num.min(row_size.min(column_size) + 1) {
+        for u in us.iter() {
+            p = &p - &(u.dot(&p) * u);
+        }
+        let alpha = p.norm_l2();
+        diag.push(alpha * alpha + prev_beta * prev_beta);
+        if k > 0 {
+            off.push(prev_alpha * prev_beta);
+        }
+        //the Ritz value is non-decreasing
+        ritz_value = tridiag_max_eigenvalue(&diag, &off, ritz_value);
+        residual = 0.;
+        //invariant subspace
+        if alpha <= F64_EPS * ritz_value.sqrt() {
+            break;
+        }
+        us.push(p / alpha);
+
+        let mut w = mat.t_mul_vec(&us[k]) - alpha * &vs[k];
+        for v in vs.iter() {
+            w = &w - &(v.dot(&w) * v);
+        }
+        let beta = w.norm_l2();
+        //||A^T A V y - σ^2 V y||_2 = αβ|y_k| for the Ritz vector V y
+        let ritz_vec = tridiag_max_eigenvector(&diag, &off, ritz_value);
+        residual = alpha * beta * ritz_vec[k].abs();
+        if residual <= tolerance * ritz_value || beta <= F64_EPS * ritz_value.sqrt() {
+            break;
+        }
+        vs.push(w / beta);
+        p = mat.mul_vec(&vs[k + 1]) - beta * &us[k];
+        (prev_alpha, prev_beta) = (alpha, beta);
+    }
+    (ritz_value + residual).sqrt()
+}
+
+///Number of eigenvalues of the symmetric tridiagonal matrix less than x(Sturm sequence)
+fn tridiag_count_less(diag: &[f64], off: &[f64], x: f64) -> usize {
+    let mut count = 0;
+    let mut q = 1.;
+    for i in 0..diag.len() {
+        q = if i > 0 {
+            diag[i] - x - off[i - 1] * off[i - 1] / q
+        } else {
+            diag[i] - x
+        };
+        if q == 0. {
+            q = -f64::EPSILON * (x.abs() + f64::MIN_POSITIVE);
+        }
+        if q < 0. {
+            count += 1;
+        }
+    }
+    count
+}
+
+///The largest eigenvalue of the symmetric tridiagonal matrix by bisection.
+///lower is a lower bound of it, and the returned value is not less than it.
+fn tridiag_max_eigenvalue(diag: &[f64], off: &[f64], lower: f64) -> f64 {
+    //upper bound by Gershgorin circles
+    let mut upper = (0..diag.len())
+        .map(|i| {
+            let left = if i > 0 { off[i - 1].abs() } else { 0. };
+            let right = if i < off.len() { off[i].abs() } else { 0. };
+            diag[i] + left + right
+        })
+        .fold(f64::MIN, f64::max);
+    let mut lower = lower.min(upper);
+    while upper - lower > f64::EPSILON * upper.abs() {
+        let middle = 0.5 * (lower + upper);
+        if middle <= lower || middle >= upper {
+            break;
+        }
+        if tridiag_count_less(diag, off, middle) == diag.len() {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+    upper
+}
+
+///Normalized eigenvector of the largest eigenvalue by inverse iteration.
+///(σI - T) is positive definite for the shift σ just above eigenvalue, so it is solved without pivoting.
+fn tridiag_max_eigenvector(diag: &[f64], off: &[f64], eigenvalue: f64) -> Array1<f64> {
+    let n = diag.len();
+    let shift = eigenvalue + 1e-10 * eigenvalue.abs() + f64::MIN_POSITIVE;
+    let mut vec: Array1<f64> = Array::ones(n) / (n as f64).sqrt();
+    for _ in 0..2 {
+        //forward elimination and back substitution of (σI - T) z = vec
+        let mut pivots = vec![0.; n];
+        let mut rhs = vec.to_vec();
+        for i in 0..n {
+            pivots[i] = shift - diag[i];
+            if i > 0 {
+                pivots[i] -= off[i - 1] * off[i - 1] / pivots[i - 1];
+                rhs[i] += off[i - 1] / pivots[i - 1] * rhs[i - 1];
+            }
+            pivots[i] = pivots[i].max(f64::MIN_POSITIVE);
+        }
+        for i in (0..n).rev() {
+            let next = if i + 1 < n { off[i] * vec[i + 1] } else { 0. };
+            vec[i] = (rhs[i] + next) / pivots[i];
+        }
+        let norm = vec.norm_l2();
+        vec /= norm;
+    }
+    vec
 }
 
 ///Mutal coherence for Array2<f64>.
@@ -40,14 +313,15 @@ pub fn matrix_l2(mat: &Array2<f64>) -> f64 {
 ///assert_eq!(0.9899494936611665, sparse_modeling::math_func::mutal_coherence(&a));
 /// ```
 #[allow(dead_code)]
-pub fn mutal_coherence(mat: &Array2<f64>) -> f64 {
-    let mut mat_sub = mat.clone();
-    mat_sub = normalize_columns(&mat_sub).expect("can't normalize columns");
-    let mut gram = mat_sub.t().dot(&mat_sub);
-    for i in 0..gram.shape()[0] {
-        gram[[i, i]] = 0.;
-    }
-    gram.norm_max()
+pub fn mutal_coherence<M: LinearOperator + ColumnScale>(mat: &M) -> f64 {
+    let mat_sub = normalize_columns(mat).expect("can't normalize columns");
+    (0..mat_sub.shape().1)
+        .map(|j| {
+            let mut gram_column = mat_sub.t_mul_vec(&mat_sub.column_vec(j));
+            gram_column[j] = 0.;
+            gram_column.norm_max()
+        })
+        .fold(0., f64::max)
 }
 
 ///Babel function for Array2<f64>.
@@ -84,7 +358,7 @@ pub fn babel_func(mat: &Array2<f64>, p: usize) -> Result<f64> {
     Ok(max)
 }
 
-///Normalize columns of mat(Array2<f64>).
+///Normalize columns of mat(Array2<f64>, CsrMatrix or CscMatrix).
 /// 
 /// # Examples
 /// 
@@ -113,51 +387,38 @@ pub fn babel_func(mat: &Array2<f64>, p: usize) -> Result<f64> {
 /// # Errors
 /// if 0 column exists return Err.
 #[allow(dead_code)]
-pub fn normalize_columns(mat: &Array2<f64>) -> Result<Array2<f64>> {
-    let mut result_mat = mat.clone();
-    for i in 0..result_mat.shape()[1] {
-        let column = result_mat.slice_mut(s![..;1, i]);
-        let l2_norm = column.norm_l2();
-        if l2_norm == 0.0 {
-            for x in column {
-                *x = 0.;
-            }
-        } else {
-            for x in column {
-                *x = *x / l2_norm;
-        }
-        }
-    }
-    Ok(result_mat)
+pub fn normalize_columns<M: LinearOperator + ColumnScale>(mat: &M) -> Result<M> {
+    //zero columns are left as they are
+    let divisors = mat
+        .column_norms()
+        .mapv(|l2_norm| if l2_norm == 0.0 { 1. } else { l2_norm });
+    Ok(mat.div_columns(&divisors))
 }
 
 ///Least suqres method with limitation of support
-pub fn lsm_with_support(
-    mat: &Array2<f64>,
+pub fn lsm_with_support<M: LinearOperator + ?Sized>(
+    mat: &M,
     y: &Array1<f64>,
     support: &HashSet<usize>,
 ) -> Result<Array1<f64>> {
-    if mat.shape()[0] != y.shape()[0] {
+    if mat.shape().0 != y.shape()[0] {
         return Err(anyhow!("mat's row size and y's size are different"));
     }
     if support.is_empty() {
         return Err(anyhow!("support is empty."));
     }
 
-    let mat_sub = columns_to_2darray(
-        mat.shape()[0],
-        support
-            .clone()
-            .into_iter()
-            .filter(|i| *i < mat.shape()[1])
-            .map(|i| mat.slice(s![.., i]).to_owned()),
-    )
-    .unwrap();
-
-    let mut x = Array::zeros(mat.shape()[1]);
-    let x_sub = pseudo_inverse(&mat_sub)
-        .expect("can't compute pseudo inverse")
-        .dot(y);
+    let mut support: Vec<usize> = support
+        .iter()
+        .copied()
+        .filter(|i| *i < mat.shape().1)
+        .collect();
+    support.sort_unstable();
+    let mat_sub = columns_to_2darray(mat.shape().0, support.iter().map(|i| mat.column_vec(*i)))?;
+
+    //minimum norm solution by the pseudo inverse of the small submatrix
+    let mut x = Array::zeros(mat.shape().1);
+    let x_sub = pseudo_inverse(&mat_sub)?.dot(y);
     support.iter().enumerate().for_each(|(sub_i, x_i)| {
         x[*x_i] = x_sub[sub_i];
     });
@@ -165,6 +426,93 @@ pub fn lsm_with_support(
     Ok(x)
 }
 
+///Non-negative least squares(minimize ||y - Ax||_2 s.t. x >= 0) by Lawson-Hanson active set method.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+///
+///let mat = array![[1., 0.], [0., 1.]];
+///let x = sparse_modeling::math_func::nnls(&mat, &array![1., -1.]).unwrap();
+///assert_eq!(x, array![1., 0.]);
+/// ```
+pub fn nnls<M: LinearOperator + ?Sized>(mat: &M, y: &Array1<f64>) -> Result<Array1<f64>> {
+    nnls_with_support(mat, y, &(0..mat.shape().1).collect())
+}
+
+///Non-negative least squares whose nonzero elements are restricted to support.
+pub fn nnls_with_support<M: LinearOperator + ?Sized>(
+    mat: &M,
+    y: &Array1<f64>,
+    support: &HashSet<usize>,
+) -> Result<Array1<f64>> {
+    if mat.shape().0 != y.shape()[0] {
+        return Err(anyhow!("mat's row size and y's size are different"));
+    }
+
+    //passive set(positive elements) and candidates
+    let mut passive: HashSet<usize> = HashSet::new();
+    let mut candidates: Vec<usize> = support
+        .iter()
+        .copied()
+        .filter(|i| *i < mat.shape().1)
+        .collect();
+    candidates.sort_unstable();
+    let tolerance = F64_EPS * mat.t_mul_vec(y).norm_max().max(1.);
+    let mut x: Array1<f64> = Array::zeros(mat.shape().1);
+    let mut w = mat.t_mul_vec(y);
+
+    for _ in 0..3 * candidates.len() {
+        //add the candidate with the largest gradient
+        let target = candidates
+            .iter()
+            .filter(|i| !passive.contains(i))
+            .max_by(|a, b| w[**a].partial_cmp(&w[**b]).unwrap());
+        match target {
+            Some(i) if w[*i] > tolerance => passive.insert(*i),
+            _ => break,
+        };
+
+        //least squares on the passive set, keeping x feasible
+        let mut s = lsm_with_support(mat, y, &passive)?;
+        //x[i] == s[i](e.g. both are 0) doesn't restrict the step
+        while let Some(alpha) = passive
+            .iter()
+            .filter(|i| s[**i] <= 0. && x[**i] != s[**i])
+            .map(|i| x[*i] / (x[*i] - s[*i]))
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+        {
+            x = &x + &(alpha * (&s - &x));
+            passive.retain(|i| x[*i] > tolerance);
+            x.indexed_iter_mut()
+                .filter(|(i, _)| !passive.contains(i))
+                .for_each(|(_, v)| *v = 0.);
+            if passive.is_empty() {
+                s = Array::zeros(mat.shape().1);
+                break;
+            }
+            s = lsm_with_support(mat, y, &passive)?;
+        }
+        x = s;
+
+        w = mat.t_mul_vec(&(y - mat.mul_vec(&x)));
+    }
+
+    //KKT conditions aren't satisfied if the iterations run out
+    if candidates
+        .iter()
+        .any(|i| !passive.contains(i) && w[*i] > tolerance)
+    {
+        return Err(anyhow!(format!(
+            "nnls didn't converge in {} iterations",
+            3 * candidates.len()
+        )));
+    }
+
+    Ok(x)
+}
+
 ///Integrate columns into 2d-array
 /// 
 /// # Examples
@@ -292,6 +640,17 @@ pub fn support(vec: &Array1<f64>, err_range: f64) -> HashSet<usize> {
     supp
 }
 
+///Return row support of input Array2<f64>(rows whose l2 norm is not zero)
+///err_range is eps. if a row's l2 norm is less than err_range / 2. we treat it as zero.
+pub fn row_support(mat: &Array2<f64>, err_range: f64) -> HashSet<usize> {
+    mat.rows()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, row)| row.norm_l2() > err_range * 0.5)
+        .map(|(i, _)| i)
+        .collect()
+}
+
 ///L2 relative error of two Array2<f64> inputs
 /// return ||exact_x - estimated_x||_2 / ||exact_x||_2
 #[allow(dead_code)]
@@ -317,13 +676,28 @@ pub fn l2_relative_err(exact_x: &Array1<f64>, estimated_x: &Array1<f64>) -> Resu
     Ok(diff_norm / exact_x_norm)
 }
 
+/// judge whether (mat, Y) is under estimated system of multiple measurement vectors
+pub fn is_underestimated_mmv_sys(mat: &Array2<f64>, y: &Array2<f64>) -> Result<()> {
+    if mat.nrows() != y.nrows() || mat.nrows() > mat.ncols() {
+        return Err(anyhow!(format!(
+            "mat's shape is {}x{} / y's shape is {}x{}",
+            mat.nrows(),
+            mat.ncols(),
+            y.nrows(),
+            y.ncols()
+        )));
+    }
+    Ok(())
+}
+
 /// judge whether (mat, y) is under estimated system
-pub fn is_underestimated_sys(mat: &Array2<f64>, y: &Array1<f64>) -> Result<()> {
-    if mat.shape()[0] != y.shape()[0] || mat.shape()[0] > mat.shape()[1] {
+pub fn is_underestimated_sys<M: LinearOperator + ?Sized>(mat: &M, y: &Array1<f64>) -> Result<()> {
+    let (rows, cols) = mat.shape();
+    if rows != y.shape()[0] || rows > cols {
         return Err(anyhow!(format!(
             "mat's shape is {}x{} / y's size is {}",
-            mat.shape()[0],
-            mat.shape()[1],
+            rows,
+            cols,
             y.shape()[0]
         )
         .to_string()));
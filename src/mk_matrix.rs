@@ -22,3 +22,55 @@ pub fn mk_idft_mat(n: usize) -> Array2<c64> {
     let matrix = mk_dft_mat(n);
     generate::conjugate(&matrix)
 }
+
+///Make a random matrix whose elements are drawn uniformly from [min, max).
+pub fn rand_uniform_mat<R: Rng + ?Sized>(
+    rng: &mut R,
+    shape: (usize, usize),
+    min: f64,
+    max: f64,
+) -> Array2<f64> {
+    ArrayBase::from_shape_fn(shape, |_| rng.gen_range(min..max))
+}
+
+///Make a random matrix whose elements are drawn from N(0, std_dev^2).
+pub fn rand_gaussian_mat<R: Rng + ?Sized>(
+    rng: &mut R,
+    shape: (usize, usize),
+    std_dev: f64,
+) -> Array2<f64> {
+    ArrayBase::from_shape_fn(shape, |_| std_dev * rng.sample::<f64, _>(StandardNormal))
+}
+
+///Make a finite difference matrix((n-1) x n), whose i-th row is e_(i+1) - e_i.
+pub fn mk_diff_mat(n: usize) -> Array2<f64> {
+    ArrayBase::from_shape_fn((n.saturating_sub(1), n), |(i, j)| {
+        if j == i + 1 {
+            1.
+        } else if j == i {
+            -1.
+        } else {
+            0.
+        }
+    })
+}
+
+///Make a redundant DCT matrix(p x n, p >= n) with rows cos(π k (j + 1/2) / p) normalized to unit l2 norm.
+pub fn mk_redundant_dct_mat(p: usize, n: usize) -> Result<Array2<f64>> {
+    if p < n {
+        return Err(anyhow!(format!(
+            "p is {} and n is {}, p is needed to be larger than or equal to n",
+            p, n
+        )));
+    }
+    let mut mat = ArrayBase::from_shape_fn((p, n), |(k, j)| {
+        f64::cos(PI * k as f64 * (j as f64 + 0.5) / p as f64)
+    });
+    for mut row in mat.rows_mut() {
+        let norm = row.norm_l2();
+        if norm > 0. {
+            row /= norm;
+        }
+    }
+    Ok(mat)
+}
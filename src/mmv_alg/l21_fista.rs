@@ -0,0 +1,57 @@
+//! # MMV FISTA
+//!
+//! FISTA for l2,1 regularized least squares
+//! (minimize (1/2)||Y - AX||_F^(2) + λ Σ_i ||X_i||_2, X_i is the i-th row).
+use crate::prelude::*;
+
+pub struct MmvFista {
+    lambda: f64,
+    iter_num: usize,
+    threshold: f64,
+}
+
+impl MmvFista {
+    #[allow(dead_code)]
+    pub fn new(lambda: f64, iter_num: usize, threshold: f64) -> Self {
+        Self {
+            lambda,
+            iter_num,
+            threshold,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, lambda: f64, iter_num: usize, threshold: f64) {
+        self.lambda = lambda;
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+    }
+}
+
+impl MmvAlg for MmvFista {
+    fn solve(&self, mat: &Array2<f64>, y: &Array2<f64>) -> Result<Array2<f64>> {
+        is_underestimated_mmv_sys(mat, y)?;
+
+        //initialization
+        let mut x: Array2<f64> = Array::zeros((mat.ncols(), y.ncols()));
+        let mut prev_x;
+        let mut z = x.clone();
+        let lipshitz = matrix_l2(mat).powi(2);
+        let mut beta = 1.;
+        let mut prev_beta;
+
+        for _ in 0..self.iter_num {
+            prev_x = x.clone();
+            let v = &z + &(mat.t().dot(&(y - mat.dot(&z))) / lipshitz);
+            x = row_st_array2(self.lambda / lipshitz, &v);
+            prev_beta = beta;
+            beta = (1. + (1. + 4. * beta * beta).sqrt()) * 0.5;
+            z = &x + &((prev_beta - 1.) / beta * (&x - &prev_x));
+
+            if (&x - &prev_x).norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        Ok(x)
+    }
+}
@@ -0,0 +1,20 @@
+//! # MMV Alg
+//!
+//! 'mmv_alg' is a collection of algorithms for multiple measurement vectors(MMV).
+//! They calculate joint sparse solutions X of Y = AX, whose rows share the support.
+
+mod l21_fista;
+mod msbl;
+mod somp;
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+pub use l21_fista::MmvFista;
+pub use msbl::Msbl;
+pub use somp::Somp;
+
+pub trait MmvAlg {
+    fn solve(&self, mat: &Array2<f64>, y: &Array2<f64>) -> Result<Array2<f64>>;
+}
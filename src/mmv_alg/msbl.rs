@@ -0,0 +1,97 @@
+//! # M-SBL
+//!
+//! Multiple measurement vectors sparse bayesian learning(EM updates of hyperparameters γ).
+//! Each row X_i follows N(0, γ_i I) and noise follows N(0, noise_var I).
+use crate::prelude::*;
+
+pub struct Msbl {
+    noise_var: f64,
+    threshold: f64,
+    iter_num: usize,
+}
+
+impl Msbl {
+    //γ less than PRUNE_GAMMA is treated as zero
+    const PRUNE_GAMMA: f64 = 1e-8;
+
+    #[allow(dead_code)]
+    pub fn new(noise_var: f64, threshold: f64, iter_num: usize) -> Result<Self> {
+        if noise_var <= 0. {
+            return Err(anyhow!(format!(
+                "noise_var is {}, it is needed to be positive",
+                noise_var
+            )));
+        }
+        Ok(Self {
+            noise_var,
+            threshold,
+            iter_num,
+        })
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, noise_var: f64, threshold: f64, iter_num: usize) -> Result<()> {
+        if noise_var <= 0. {
+            return Err(anyhow!(format!(
+                "noise_var is {}, it is needed to be positive",
+                noise_var
+            )));
+        }
+        self.noise_var = noise_var;
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+        Ok(())
+    }
+}
+
+impl MmvAlg for Msbl {
+    fn solve(&self, mat: &Array2<f64>, y: &Array2<f64>) -> Result<Array2<f64>> {
+        is_underestimated_mmv_sys(mat, y)?;
+
+        //initialization
+        let (m, n) = (mat.nrows(), mat.ncols());
+        let channel_num = y.ncols() as f64;
+        let mut gamma: Array1<f64> = Array::ones(n);
+        let mut prev_gamma;
+        let mut mu: Array2<f64> = Array::zeros((n, y.ncols()));
+
+        for _ in 0..self.iter_num {
+            prev_gamma = gamma.clone();
+
+            //Σ_y = σ^2 I + A Γ A^T
+            let mat_gamma = mat * &gamma.view().insert_axis(Axis(0));
+            let mut sigma_y = mat_gamma.dot(&mat.t());
+            for i in 0..m {
+                sigma_y[[i, i]] += self.noise_var;
+            }
+            let sigma_y_inv = sigma_y
+                .inv()
+                .map_err(|_| anyhow!("can't compute inverse of Σ_y"))?;
+
+            //posterior mean M = Γ A^T Σ_y^(-1) Y and diagonal of Σ_x = Γ - Γ A^T Σ_y^(-1) A Γ
+            let temp = mat_gamma.t().dot(&sigma_y_inv);
+            mu = temp.dot(y);
+            let sigma_x_diag: Array1<f64> =
+                Array::from_shape_fn(n, |i| gamma[i] - temp.row(i).dot(&mat_gamma.column(i)));
+
+            //update γ
+            for i in 0..n {
+                gamma[i] = mu.row(i).norm_l2().powi(2) / channel_num + sigma_x_diag[i];
+                if gamma[i] < Self::PRUNE_GAMMA {
+                    gamma[i] = 0.;
+                }
+            }
+
+            if (&gamma - &prev_gamma).norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        for i in 0..n {
+            if gamma[i] == 0. {
+                mu.row_mut(i).fill(0.);
+            }
+        }
+
+        Ok(mu)
+    }
+}
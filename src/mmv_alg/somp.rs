@@ -0,0 +1,73 @@
+//! # SOMP
+//!
+//! Simultaneous orthogonal matching pursuit
+use crate::prelude::*;
+
+pub struct Somp {
+    threshold: f64,
+    iter_num: usize,
+}
+
+impl Somp {
+    #[allow(dead_code)]
+    pub fn new(threshold: f64, iter_num: usize) -> Self {
+        Self {
+            threshold,
+            iter_num,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, threshold: f64, iter_num: usize) {
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+    }
+}
+
+impl MmvAlg for Somp {
+    fn solve(&self, mat: &Array2<f64>, y: &Array2<f64>) -> Result<Array2<f64>> {
+        is_underestimated_mmv_sys(mat, y)?;
+
+        //initialization
+        let mat_normalized = normalize_columns(mat)?;
+        let mut x: Array2<f64> = Array::zeros((mat.ncols(), y.ncols()));
+        let mut r = y.clone();
+        let mut support = Vec::new();
+
+        for _ in 0..cmp::min(mat.ncols(), self.iter_num) {
+            //全チャンネルの残差の射影(l2ノルム)が最大となる列探索
+            let (target_idx, _) = mat_normalized
+                .t()
+                .dot(&r)
+                .rows()
+                .into_iter()
+                .map(|row| row.norm_l2())
+                .enumerate()
+                .filter(|(i, _)| !support.contains(i))
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                .expect("failed to get max projection");
+
+            //support update
+            support.push(target_idx);
+
+            //update tentative solution(x)
+            let mat_sub = columns_to_2darray(
+                mat.nrows(),
+                support.iter().map(|i| mat.column(*i).to_owned()),
+            )?;
+            let x_sub = pseudo_inverse(&mat_sub)?.dot(y);
+            x.fill(0.);
+            for (sub_i, x_i) in support.iter().enumerate() {
+                x.row_mut(*x_i).assign(&x_sub.row(sub_i));
+            }
+
+            //update residual(r)
+            r = y - mat.dot(&x);
+
+            if r.norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        Ok(x)
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn mmv_1sample_test() {
+    let matrix_shape = (30, 50);
+    let channel_num = 5;
+    let row_num = 8;
+    let supp_err_range = 1e-2;
+
+    let mut rng = test_rng();
+    let matrix = normalize_columns(&rand_gaussian_mat(&mut rng, matrix_shape, 1.)).unwrap();
+    let input_data = rand_row_sparse_signal(&mut rng, matrix_shape.1, channel_num, row_num, 1.)
+        .expect("can't generate a signal");
+    let output_data = matrix.dot(&input_data);
+
+    let somp = Somp::new(1e-8, 100);
+    let somp_result = somp.solve(&matrix, &output_data).unwrap();
+    let fista = MmvFista::new(1e-3, 5000, 1e-12);
+    let fista_result = fista.solve(&matrix, &output_data).unwrap();
+    let msbl = Msbl::new(1e-6, 1e-8, 1000).unwrap();
+    let msbl_result = msbl.solve(&matrix, &output_data).unwrap();
+
+    let exact_supp = row_support(&input_data, supp_err_range);
+    for (name, result) in [
+        ("somp", &somp_result),
+        ("fista", &fista_result),
+        ("msbl", &msbl_result),
+    ] {
+        let rel_err = (&input_data - result).norm_l2() / input_data.norm_l2();
+        println!(
+            "{}|| l2_relative_err: {}, row support: {:?}",
+            name,
+            rel_err,
+            row_support(result, supp_err_range)
+        );
+        assert!(rel_err < 0.1);
+        assert_eq!(row_support(result, supp_err_range), exact_supp);
+    }
+}
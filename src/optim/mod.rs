@@ -0,0 +1,367 @@
+//! # Optim
+//!
+//! Proximal gradient method for composite problems
+//! (minimize f(x) + g(x), f is a SmoothLoss and g is given by its proximal operator(Prox)).
+//! ISTA/FISTA, fixed step/backtracking and restart of the momentum are options of ProxGrad.
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+pub trait SmoothLoss {
+    ///Size of x
+    fn dim(&self) -> usize;
+    fn value(&self, x: &Array1<f64>) -> f64;
+    fn gradient(&self, x: &Array1<f64>) -> Array1<f64>;
+    ///Upper bound of the Lipschitz constant of the gradient(None if it is not known)
+    fn lipshitz(&self) -> Option<f64> {
+        None
+    }
+}
+
+pub trait Prox {
+    fn value(&self, x: &Array1<f64>) -> f64;
+    ///argmin_x step g(x) + (1/2)||x - v||^(2)
+    fn prox(&self, step: f64, v: &Array1<f64>) -> Array1<f64>;
+}
+
+///(1/2)||y - Ax||^(2)
+pub struct LeastSquaresLoss<'a> {
+    mat: &'a dyn LinearOperator,
+    y: &'a Array1<f64>,
+}
+
+impl<'a> LeastSquaresLoss<'a> {
+    pub fn new(mat: &'a dyn LinearOperator, y: &'a Array1<f64>) -> Result<Self> {
+        if mat.shape().0 != y.len() {
+            return Err(anyhow!("mat's row size and y's size are different"));
+        }
+        Ok(Self { mat, y })
+    }
+}
+
+impl SmoothLoss for LeastSquaresLoss<'_> {
+    fn dim(&self) -> usize {
+        self.mat.shape().1
+    }
+    fn value(&self, x: &Array1<f64>) -> f64 {
+        0.5 * (self.y - &self.mat.mul_vec(x)).norm_l2().powi(2)
+    }
+    fn gradient(&self, x: &Array1<f64>) -> Array1<f64> {
+        -1. * self.mat.t_mul_vec(&(self.y - &self.mat.mul_vec(x)))
+    }
+    fn lipshitz(&self) -> Option<f64> {
+        Some(matrix_l2(self.mat).powi(2))
+    }
+}
+
+///Huber loss Σ_i h_δ((y - Ax)_i), h_δ(r) = r^2 / 2 if |r| <= δ and δ(|r| - δ/2) otherwise
+pub struct HuberLoss<'a> {
+    mat: &'a dyn LinearOperator,
+    y: &'a Array1<f64>,
+    delta: f64,
+}
+
+impl<'a> HuberLoss<'a> {
+    pub fn new(mat: &'a dyn LinearOperator, y: &'a Array1<f64>, delta: f64) -> Result<Self> {
+        if mat.shape().0 != y.len() {
+            return Err(anyhow!("mat's row size and y's size are different"));
+        }
+        if delta <= 0. {
+            return Err(anyhow!(format!(
+                "delta is {}, it is needed to be positive",
+                delta
+            )));
+        }
+        Ok(Self { mat, y, delta })
+    }
+}
+
+impl SmoothLoss for HuberLoss<'_> {
+    fn dim(&self) -> usize {
+        self.mat.shape().1
+    }
+    fn value(&self, x: &Array1<f64>) -> f64 {
+        (self.y - &self.mat.mul_vec(x))
+            .iter()
+            .map(|r| {
+                if r.abs() <= self.delta {
+                    0.5 * r * r
+                } else {
+                    self.delta * (r.abs() - 0.5 * self.delta)
+                }
+            })
+            .sum()
+    }
+    fn gradient(&self, x: &Array1<f64>) -> Array1<f64> {
+        let psi = (self.y - &self.mat.mul_vec(x)).mapv(|r| r.clamp(-self.delta, self.delta));
+        -1. * self.mat.t_mul_vec(&psi)
+    }
+    fn lipshitz(&self) -> Option<f64> {
+        Some(matrix_l2(self.mat).powi(2))
+    }
+}
+
+///λ Σ_i w_i |x_i| with non-negative weights w
+pub struct WeightedL1Prox {
+    lambda: f64,
+    weights: Array1<f64>,
+}
+
+impl WeightedL1Prox {
+    pub fn new(lambda: f64, weights: Array1<f64>) -> Result<Self> {
+        if weights.iter().any(|w| *w < 0.) {
+            return Err(anyhow!("weights are needed to be non-negative"));
+        }
+        Ok(Self { lambda, weights })
+    }
+}
+
+impl Prox for WeightedL1Prox {
+    fn value(&self, x: &Array1<f64>) -> f64 {
+        self.lambda * (&self.weights * &x.mapv(f64::abs)).sum()
+    }
+    fn prox(&self, step: f64, v: &Array1<f64>) -> Array1<f64> {
+        Array::from_iter(
+            self.weights
+                .iter()
+                .zip(v.iter())
+                .map(|(w, v)| st(step * self.lambda * w, *v)),
+        )
+    }
+}
+
+///λ||x||_(1) restricted to the constraint set
+pub struct L1Prox<'a> {
+    lambda: f64,
+    constraint: &'a Constraint,
+}
+
+impl<'a> L1Prox<'a> {
+    pub fn new(lambda: f64, constraint: &'a Constraint) -> Self {
+        Self { lambda, constraint }
+    }
+}
+
+impl Prox for L1Prox<'_> {
+    fn value(&self, x: &Array1<f64>) -> f64 {
+        self.lambda * x.norm_l1()
+    }
+    fn prox(&self, step: f64, v: &Array1<f64>) -> Array1<f64> {
+        self.constraint.prox_l1(step * self.lambda, v)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Acceleration {
+    ///ISTA
+    #[default]
+    None,
+    ///FISTA(Nesterov's momentum)
+    Fista,
+    ///MFISTA(FISTA whose objective is non-increasing)
+    MonotoneFista,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepSize {
+    ///step 1/L with the given Lipschitz constant L(None: the loss's estimate)
+    Fixed(Option<f64>),
+    ///L is multiplied by rate until the quadratic majorizer bounds the loss.
+    ///It starts from init(None: the loss's estimate or 1).
+    Backtracking { init: Option<f64>, rate: f64 },
+}
+
+impl Default for StepSize {
+    fn default() -> Self {
+        StepSize::Fixed(None)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Restart {
+    #[default]
+    None,
+    ///reset the momentum when the objective increases
+    Function,
+    ///reset the momentum when the momentum and the gradient mapping make an acute angle
+    Gradient,
+}
+
+pub struct ProxGrad {
+    iter_num: usize,
+    threshold: f64,
+    acceleration: Acceleration,
+    step_size: StepSize,
+    restart: Restart,
+}
+
+impl ProxGrad {
+    #[allow(dead_code)]
+    pub fn new(iter_num: usize, threshold: f64) -> Self {
+        Self {
+            iter_num,
+            threshold,
+            acceleration: Acceleration::None,
+            step_size: StepSize::Fixed(None),
+            restart: Restart::None,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, iter_num: usize, threshold: f64) {
+        self.iter_num = iter_num;
+        self.threshold = threshold;
+    }
+    #[allow(dead_code)]
+    pub fn set_acceleration(&mut self, acceleration: Acceleration) {
+        self.acceleration = acceleration;
+    }
+    #[allow(dead_code)]
+    pub fn set_step_size(&mut self, step_size: StepSize) {
+        self.step_size = step_size;
+    }
+    ///Restart is used only with acceleration
+    #[allow(dead_code)]
+    pub fn set_restart(&mut self, restart: Restart) {
+        self.restart = restart;
+    }
+
+    ///minimize loss(x) + prox's g(x) from x0 and return the solution with the number of iterations.
+    ///It stops when ||x_(k+1) - x_(k)||_(2) < threshold.
+    pub fn minimize<L: SmoothLoss + ?Sized, P: Prox + ?Sized>(
+        &self,
+        loss: &L,
+        prox: &P,
+        x0: &Array1<f64>,
+    ) -> Result<(Array1<f64>, usize)> {
+        //check data
+        if x0.len() != loss.dim() {
+            return Err(anyhow!(format!(
+                "x0's size is {}, but the loss needs {}",
+                x0.len(),
+                loss.dim()
+            )));
+        }
+        let mut lipshitz = match self.step_size {
+            StepSize::Fixed(Some(lip)) => lip,
+            StepSize::Fixed(None) => loss.lipshitz().ok_or(anyhow!(
+                "the Lipschitz constant of the loss is unknown, use backtracking"
+            ))?,
+            StepSize::Backtracking { init, rate } => {
+                if rate <= 1. {
+                    return Err(anyhow!(format!(
+                        "backtracking rate is {}, it is needed to be larger than 1",
+                        rate
+                    )));
+                }
+                init.or(loss.lipshitz()).unwrap_or(1.)
+            }
+        };
+        if !(lipshitz > 0. && lipshitz.is_finite()) {
+            return Err(anyhow!(format!(
+                "the Lipschitz constant is {}, it is needed to be positive",
+                lipshitz
+            )));
+        }
+
+        //initialization
+        let mut x = x0.clone();
+        let mut prev_x;
+        let mut z = x0.clone();
+        let mut beta: f64 = 1.;
+        let mut prev_beta;
+        let mut objective = if self.restart == Restart::Function
+            || self.acceleration == Acceleration::MonotoneFista
+        {
+            loss.value(&x) + prox.value(&x)
+        } else {
+            0.
+        };
+
+        let mut iter = 0;
+        for _ in 0..self.iter_num {
+            iter += 1;
+            prev_x = x.clone();
+            let grad_z = loss.gradient(&z);
+            //proximal gradient step from z
+            let u = match self.step_size {
+                StepSize::Fixed(_) => prox.prox(1. / lipshitz, &(&z - &(&grad_z / lipshitz))),
+                StepSize::Backtracking { rate, .. } => {
+                    let value_z = loss.value(&z);
+                    if !value_z.is_finite() {
+                        return Err(anyhow!(format!(
+                            "the loss is {} at iteration {}",
+                            value_z, iter
+                        )));
+                    }
+                    //メジャライザーが損失を上回るまでlipshitzを増加させる
+                    loop {
+                        let candidate = prox.prox(1. / lipshitz, &(&z - &(&grad_z / lipshitz)));
+                        let diff = &candidate - &z;
+                        let majorizer =
+                            value_z + grad_z.dot(&diff) + 0.5 * lipshitz * diff.dot(&diff);
+                        let value = loss.value(&candidate);
+                        if value.is_finite() && value <= majorizer + F64_EPS * value_z.abs() {
+                            break candidate;
+                        }
+                        lipshitz *= rate;
+                        if !lipshitz.is_finite() {
+                            return Err(anyhow!(format!(
+                                "backtracking didn't find a step size at iteration {}",
+                                iter
+                            )));
+                        }
+                    }
+                }
+            };
+
+            let mut restart = false;
+            if self.acceleration != Acceleration::None && self.restart == Restart::Gradient {
+                restart = (&z - &u).dot(&(&u - &prev_x)) > 0.;
+            }
+            if self.restart == Restart::Function || self.acceleration == Acceleration::MonotoneFista
+            {
+                let prev_objective = objective;
+                let objective_u = loss.value(&u) + prox.value(&u);
+                if self.restart == Restart::Function && objective_u > prev_objective {
+                    restart = true;
+                }
+                //MFISTA keeps the previous x if u increases the objective
+                //(u from z = x is a descent step and the increase is a rounding error)
+                x = if self.acceleration == Acceleration::MonotoneFista
+                    && objective_u > prev_objective
+                    && z != prev_x
+                {
+                    prev_x.clone()
+                } else {
+                    objective = objective_u;
+                    u.clone()
+                };
+            } else {
+                x = u.clone();
+            }
+
+            match self.acceleration {
+                Acceleration::None => z = x.clone(),
+                Acceleration::Fista | Acceleration::MonotoneFista => {
+                    if restart {
+                        beta = 1.;
+                        z = x.clone();
+                    } else {
+                        prev_beta = beta;
+                        beta = (1. + (1. + 4. * beta * beta).sqrt()) * 0.5;
+                        z = &x
+                            + &(prev_beta / beta * (&u - &x))
+                            + &((prev_beta - 1.) / beta * (&x - &prev_x));
+                    }
+                }
+            }
+
+            if (&u - &prev_x).norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        Ok((x, iter))
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+use crate::glm::PoissonLoss;
+
+#[test]
+fn prox_grad_test() {
+    let mut rng = test_rng();
+    let input_data: Array1<f64> =
+        rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate a signal");
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    let output_data = noisy_measurement(&mut rng, &matrix, &input_data, 30.).unwrap();
+    let lambda = 0.1;
+    let exact = LassoHomotopy::new(1000)
+        .path(&matrix, &output_data, lambda)
+        .unwrap()
+        .solution_at(lambda);
+
+    let loss = LeastSquaresLoss::new(&matrix, &output_data).unwrap();
+    let constraint = Constraint::Unconstrained;
+    let prox = L1Prox::new(lambda, &constraint);
+    let x0 = Array::zeros(50);
+    let backtracking = StepSize::Backtracking {
+        init: Some(1.),
+        rate: 2.,
+    };
+    for (acceleration, step_size, restart) in [
+        (Acceleration::None, StepSize::Fixed(None), Restart::None),
+        (Acceleration::None, backtracking, Restart::None),
+        (Acceleration::Fista, StepSize::Fixed(None), Restart::None),
+        (Acceleration::Fista, backtracking, Restart::None),
+        (
+            Acceleration::Fista,
+            StepSize::Fixed(None),
+            Restart::Function,
+        ),
+        (
+            Acceleration::Fista,
+            StepSize::Fixed(None),
+            Restart::Gradient,
+        ),
+        (
+            Acceleration::MonotoneFista,
+            StepSize::Fixed(None),
+            Restart::None,
+        ),
+        (Acceleration::MonotoneFista, backtracking, Restart::Function),
+    ] {
+        let mut optimizer = ProxGrad::new(100000, 1e-12);
+        optimizer.set_acceleration(acceleration);
+        optimizer.set_step_size(step_size);
+        optimizer.set_restart(restart);
+        let (result, iter) = optimizer.minimize(&loss, &prox, &x0).unwrap();
+        println!(
+            "{:?} {:?} {:?}: iter {}, err {}",
+            acceleration,
+            step_size,
+            restart,
+            iter,
+            (&result - &exact).norm_l2()
+        );
+        assert!((&result - &exact).norm_l2() < 1e-6);
+    }
+
+    //the objective of MFISTA is non-increasing
+    let mut optimizer = ProxGrad::new(1, 0.);
+    optimizer.set_acceleration(Acceleration::MonotoneFista);
+    let objectives: Vec<f64> = (1..100)
+        .map(|iter_num| {
+            optimizer.set(iter_num, 0.);
+            let (result, _) = optimizer.minimize(&loss, &prox, &x0).unwrap();
+            loss.value(&result) + prox.value(&result)
+        })
+        .collect();
+    assert!(objectives.windows(2).all(|w| w[1] <= w[0]));
+
+    //a loss without the Lipschitz constant needs backtracking
+    let counts = output_data.mapv(|v| v.abs().round());
+    let poisson = PoissonLoss::new(&matrix, &counts).unwrap();
+    let mut optimizer = ProxGrad::new(10, 1e-12);
+    assert!(optimizer.minimize(&poisson, &prox, &x0).is_err());
+    //backtracking stops if the loss overflows
+    optimizer.set_step_size(backtracking);
+    assert!(optimizer
+        .minimize(&poisson, &prox, &Array::from_elem(50, 1e3))
+        .is_err());
+    assert!(optimizer.minimize(&loss, &prox, &Array::zeros(3)).is_err());
+}
+
+#[test]
+fn huber_loss_and_weighted_l1_prox_test() {
+    let mut rng = test_rng();
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (20, 30), -1.0, 1.0);
+    let output_data: Array1<f64> = Array::from_shape_fn(20, |_| rng.gen_range(-2.0..2.0));
+    let x: Array1<f64> = Array::from_shape_fn(30, |_| rng.gen_range(-0.5..0.5));
+
+    //the gradient matches the finite difference
+    let huber = HuberLoss::new(&matrix, &output_data, 0.5).unwrap();
+    let gradient = huber.gradient(&x);
+    let eps = 1e-6;
+    for i in 0..30 {
+        let mut moved = x.clone();
+        moved[i] += eps;
+        let diff = (huber.value(&moved) - huber.value(&x)) / eps;
+        assert!((diff - gradient[i]).abs() < 1e-4);
+    }
+    //a large delta gives the least squares loss
+    let huber = HuberLoss::new(&matrix, &output_data, 1e6).unwrap();
+    let least_squares = LeastSquaresLoss::new(&matrix, &output_data).unwrap();
+    assert!((huber.value(&x) - least_squares.value(&x)).abs() < 1e-10);
+    assert!(HuberLoss::new(&matrix, &output_data, 0.).is_err());
+
+    let prox = WeightedL1Prox::new(2., array![1., 0., 0.5]).unwrap();
+    let v = array![3., -3., -3.];
+    assert_eq!(prox.prox(0.5, &v), array![2., -3., -2.5]);
+    assert_eq!(prox.value(&v), 2. * (3. + 1.5));
+    assert!(WeightedL1Prox::new(1., array![1., -1.]).is_err());
+}
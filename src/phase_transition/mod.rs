@@ -0,0 +1,185 @@
+//! # Phase Transition
+//!
+//! 'phase_transition' draws Donoho-Tanner phase transition diagrams of sparse algorithms.
+//! δ = m/n(undersampling ratio) and ρ = k/m(sparsity ratio) are swept over a grid,
+//! and empirical recovery probability is recorded at each cell.
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+///Criterion to judge whether a trial recovered the exact solution.
+#[derive(Clone, Copy, Debug)]
+pub enum RecoveryCriterion {
+    ///l2_relative_err(exact, estimated) < tol
+    L2RelativeErr(f64),
+    ///support_distance(exact, estimated, err_range) <= max_dist
+    SupportDistance { err_range: f64, max_dist: f64 },
+}
+
+impl RecoveryCriterion {
+    pub fn is_recovered(&self, exact_x: &Array1<f64>, estimated_x: &Array1<f64>) -> Result<bool> {
+        match *self {
+            Self::L2RelativeErr(tol) => Ok(l2_relative_err(exact_x, estimated_x)? < tol),
+            Self::SupportDistance {
+                err_range,
+                max_dist,
+            } => Ok(support_distance(exact_x, estimated_x, err_range)? <= max_dist),
+        }
+    }
+}
+
+pub struct PhaseTransition {
+    n: usize,
+    deltas: Vec<f64>,
+    rhos: Vec<f64>,
+    trial_num: usize,
+    criterion: RecoveryCriterion,
+}
+
+impl PhaseTransition {
+    ///n: signal size, deltas: grid of m/n, rhos: grid of k/m, trial_num: trials per cell
+    pub fn new(n: usize, deltas: Vec<f64>, rhos: Vec<f64>, trial_num: usize) -> Result<Self> {
+        if n == 0 {
+            return Err(anyhow!("n is needed to be positive"));
+        }
+        if trial_num == 0 {
+            return Err(anyhow!("trial_num is needed to be positive"));
+        }
+        if deltas.is_empty() || rhos.is_empty() {
+            return Err(anyhow!("deltas and rhos are needed to be non-empty"));
+        }
+        if deltas.iter().any(|d| *d <= 0. || *d > 1.) {
+            return Err(anyhow!("deltas are needed between 0 and 1"));
+        }
+        if rhos.iter().any(|r| *r <= 0. || *r > 1.) {
+            return Err(anyhow!("rhos are needed between 0 and 1"));
+        }
+        Ok(Self {
+            n,
+            deltas,
+            rhos,
+            trial_num,
+            criterion: RecoveryCriterion::L2RelativeErr(1e-2),
+        })
+    }
+    pub fn set_criterion(&mut self, criterion: RecoveryCriterion) {
+        self.criterion = criterion;
+    }
+
+    ///Run alg over the grid.
+    ///At each trial A's elements follow N(0, 1/m), x has k gaussian pulses and y = Ax.
+    ///A trial where alg returns Err is counted as a failure.
+    pub fn run<A: SparseAlg + ?Sized, R: Rng + ?Sized>(
+        &self,
+        alg: &A,
+        rng: &mut R,
+    ) -> Result<PhaseDiagram> {
+        let mut success_rate = Array::zeros((self.rhos.len(), self.deltas.len()));
+
+        for (j, delta) in self.deltas.iter().enumerate() {
+            let m = cmp::max(1, (delta * self.n as f64).round() as usize);
+            for (i, rho) in self.rhos.iter().enumerate() {
+                let k = cmp::max(1, (rho * m as f64).round() as usize);
+                let mut success_num = 0;
+                for _ in 0..self.trial_num {
+                    let mat = rand_gaussian_mat(rng, (m, self.n), 1. / (m as f64).sqrt());
+                    let x = rand_gaussian_sparse_signal(rng, self.n, k, 1.)?;
+                    let y = mat.dot(&x);
+                    let recovered = match alg.solve(&mat, &y) {
+                        Ok(estimated_x) => self.criterion.is_recovered(&x, &estimated_x)?,
+                        Err(_) => false,
+                    };
+                    if recovered {
+                        success_num += 1;
+                    }
+                }
+                success_rate[[i, j]] = success_num as f64 / self.trial_num as f64;
+            }
+        }
+
+        Ok(PhaseDiagram {
+            deltas: self.deltas.clone(),
+            rhos: self.rhos.clone(),
+            success_rate,
+        })
+    }
+}
+
+///Empirical recovery probability. success_rate[[i, j]] is the rate at (rhos[i], deltas[j]).
+pub struct PhaseDiagram {
+    pub deltas: Vec<f64>,
+    pub rhos: Vec<f64>,
+    pub success_rate: Array2<f64>,
+}
+
+impl PhaseDiagram {
+    ///Write the diagram as a matrix.
+    ///The first row is "rho\delta" and deltas, and the following rows are rho and success rates.
+    pub fn write_csv(&self, path: &str) -> Result<()> {
+        let mut writer = csv::Writer::from_path(path)?;
+
+        let mut header = vec!["rho\\delta".to_string()];
+        header.extend(self.deltas.iter().map(|d| d.to_string()));
+        writer.write_record(&header)?;
+
+        for (i, rho) in self.rhos.iter().enumerate() {
+            let mut record = vec![rho.to_string()];
+            record.extend(self.success_rate.row(i).iter().map(|p| p.to_string()));
+            writer.write_record(&record)?;
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    ///Draw the diagram as a heatmap(x: δ, y: ρ, white: always recovered, black: never recovered).
+    pub fn plot(&self, path: &str, caption: &str) -> Result<()> {
+        let delta_edges = cell_edges(&self.deltas);
+        let rho_edges = cell_edges(&self.rhos);
+
+        let root = BitMapBackend::new(path, (640, 480)).into_drawing_area();
+        root.fill(&WHITE)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption(caption, ("sans-serif", 20).into_font())
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(40)
+            .build_cartesian_2d(
+                delta_edges[0]..delta_edges[self.deltas.len()],
+                rho_edges[0]..rho_edges[self.rhos.len()],
+            )?;
+        chart
+            .configure_mesh()
+            .x_desc("delta = m/n")
+            .y_desc("rho = k/m")
+            .draw()?;
+
+        chart.draw_series(self.success_rate.indexed_iter().map(|((i, j), p)| {
+            let v = (255. * p) as u8;
+            Rectangle::new(
+                [
+                    (delta_edges[j], rho_edges[i]),
+                    (delta_edges[j + 1], rho_edges[i + 1]),
+                ],
+                RGBColor(v, v, v).filled(),
+            )
+        }))?;
+        root.present()?;
+
+        Ok(())
+    }
+}
+
+//grid points -> edges of cells centered at the points
+fn cell_edges(points: &[f64]) -> Vec<f64> {
+    if points.len() == 1 {
+        return vec![points[0] - 0.5 * points[0], points[0] + 0.5 * points[0]];
+    }
+    let mut edges = vec![points[0] - 0.5 * (points[1] - points[0])];
+    edges.extend(points.windows(2).map(|w| 0.5 * (w[0] + w[1])));
+    let last = points.len() - 1;
+    edges.push(points[last] + 0.5 * (points[last] - points[last - 1]));
+    edges
+}
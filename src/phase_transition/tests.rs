@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn omp_phase_transition_test() {
+    let n = 50;
+    let deltas: Vec<f64> = (1..=5).map(|i| 0.2 * i as f64).collect();
+    let rhos: Vec<f64> = (1..=5).map(|i| 0.2 * i as f64).collect();
+    let trial_num = 10;
+
+    let mut rng = test_rng();
+    let omp = Omp::new(1e-6, 100);
+    let phase_transition = PhaseTransition::new(n, deltas, rhos, trial_num).expect("invalid grid");
+    let diagram = phase_transition
+        .run(&omp, &mut rng)
+        .expect("failed to run phase transition");
+
+    diagram
+        .write_csv("results/omp_phase_transition.csv")
+        .expect("failed to write csv");
+    diagram
+        .plot("results/omp_phase_transition.png", "omp phase transition")
+        .expect("failed to plot");
+
+    //m = 50, k = 10 is easily recovered by omp
+    assert!(diagram.success_rate[[0, 4]] >= 0.8);
+}
+
+#[test]
+fn invalid_phase_transition_test() {
+    assert!(PhaseTransition::new(0, vec![0.5], vec![0.5], 10).is_err());
+    assert!(PhaseTransition::new(50, vec![0.5], vec![0.5], 0).is_err());
+    assert!(PhaseTransition::new(50, vec![1.5], vec![0.5], 10).is_err());
+    assert!(PhaseTransition::new(50, vec![0.5], vec![0.], 10).is_err());
+    assert!(PhaseTransition::new(50, vec![], vec![0.5], 10).is_err());
+    assert!(PhaseTransition::new(50, vec![0.5], vec![], 10).is_err());
+}
+
+struct FailingAlg;
+
+impl SparseAlg for FailingAlg {
+    fn solve(&self, _mat: &Array2<f64>, _y: &Array1<f64>) -> Result<Array1<f64>> {
+        Err(anyhow!("failed"))
+    }
+}
+
+#[test]
+fn failed_trial_test() {
+    let mut rng = test_rng();
+    let phase_transition = PhaseTransition::new(20, vec![0.5, 1.], vec![0.5], 3).unwrap();
+    let diagram = phase_transition.run(&FailingAlg, &mut rng).unwrap();
+    assert_eq!(diagram.success_rate, Array2::<f64>::zeros((1, 2)));
+}
@@ -0,0 +1,246 @@
+//! # Fast SBL
+//!
+//! Fast marginal likelihood maximization of sparse bayesian learning(Tipping & Faul).
+//! One basis function is added, re-estimated or deleted per iteration,
+//! so only the covariance of the relevant coefficients is inverted.
+use super::super::SparseAlg;
+use super::{log_marginal_likelihood, SblResult};
+use crate::prelude::*;
+
+pub struct FastSbl {
+    threshold: f64,
+    iter_num: usize,
+    noise_var: Option<f64>,
+}
+
+impl FastSbl {
+    #[allow(dead_code)]
+    pub fn new(threshold: f64, iter_num: usize) -> Self {
+        Self {
+            threshold,
+            iter_num,
+            noise_var: None,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, threshold: f64, iter_num: usize) {
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+    }
+    ///Fix the noise variance(None: estimate it together with alpha)
+    #[allow(dead_code)]
+    pub fn set_noise_var(&mut self, noise_var: Option<f64>) {
+        self.noise_var = noise_var;
+    }
+    ///Solve and return the whole posterior
+    pub fn solve_posterior(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<SblResult> {
+        fast_marginal_likelihood(
+            mat,
+            y,
+            Prior::Gaussian,
+            self.noise_var,
+            self.threshold,
+            self.iter_num,
+        )
+    }
+}
+
+impl SparseAlg for FastSbl {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_posterior(mat, y)?.mean)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        let result = self.solve_posterior(mat, y)?;
+        Ok((result.mean, Some(result.iter_num)))
+    }
+}
+
+///Prior of the variances γ_i = 1/α_i
+#[derive(Clone, Copy)]
+pub(super) enum Prior {
+    ///flat prior(type-II maximum likelihood)
+    Gaussian,
+    ///exponential prior with rate λ/2, which makes x laplace distributed.
+    ///λ is estimated if it is None.
+    Laplace(Option<f64>),
+}
+
+///Maximize l(γ) = -ln(1 + γs) + q^2 γ / (1 + γs) - λγ for γ ≥ 0
+fn optimal_gamma(s: f64, q: f64, lambda: f64) -> f64 {
+    let u = if lambda > 0. {
+        (-s + (s * s + 4. * lambda * q * q).sqrt()) / (2. * lambda)
+    } else {
+        q * q / s
+    };
+    if u > 1. {
+        (u - 1.) / s
+    } else {
+        0.
+    }
+}
+
+fn gamma_likelihood(s: f64, q: f64, lambda: f64, gamma: f64) -> f64 {
+    if gamma == 0. {
+        return 0.;
+    }
+    -(1. + gamma * s).ln() + q * q * gamma / (1. + gamma * s) - lambda * gamma
+}
+
+///Posterior covariance and mean of the relevant coefficients
+fn active_posterior(
+    mat_active: &Array2<f64>,
+    gamma_active: &Array1<f64>,
+    beta: f64,
+    y: &Array1<f64>,
+) -> Result<(Array2<f64>, Array1<f64>)> {
+    let mut temp = beta * mat_active.t().dot(mat_active);
+    for i in 0..gamma_active.len() {
+        temp[[i, i]] += 1. / gamma_active[i];
+    }
+    let cov = temp
+        .inv()
+        .map_err(|_| anyhow!("can't compute the posterior covariance"))?;
+    let mu = beta * cov.dot(&mat_active.t().dot(y));
+    Ok((cov, mu))
+}
+
+pub(super) fn fast_marginal_likelihood(
+    mat: &Array2<f64>,
+    y: &Array1<f64>,
+    prior: Prior,
+    noise_var: Option<f64>,
+    threshold: f64,
+    iter_num: usize,
+) -> Result<SblResult> {
+    is_underestimated_sys(mat, y)?;
+    if let Some(var) = noise_var {
+        if var <= 0. {
+            return Err(anyhow!(format!(
+                "noise_var is {}, it is needed to be positive",
+                var
+            )));
+        }
+    }
+
+    //initialization
+    let (m, n) = (mat.nrows(), mat.ncols());
+    let y_power = y.norm_l2().powi(2) / m as f64;
+    if y_power == 0. {
+        return Ok(SblResult {
+            mean: Array::zeros(n),
+            cov: Array::zeros((n, n)),
+            noise_precision: f64::INFINITY,
+            alpha: Array::from_elem(n, f64::INFINITY),
+            log_marginal_likelihood: Vec::new(),
+            iter_num: 0,
+        });
+    }
+    //noise variance is bounded below to keep the covariance invertible
+    let max_beta = 1. / (F64_EPS * y_power);
+    let mut beta = match noise_var {
+        Some(var) => 1. / var,
+        None => 1. / (0.1 * y_power),
+    };
+    let mut lambda = match prior {
+        Prior::Gaussian => 0.,
+        Prior::Laplace(lambda) => lambda.unwrap_or(0.),
+    };
+    let col_norms = mat.map_axis(Axis(0), |col| col.dot(&col));
+    let mat_t_y = mat.t().dot(y);
+    let mut gamma: Array1<f64> = Array::zeros(n);
+    let mut log_ml = Vec::new();
+
+    let mut iter = 0;
+    for _ in 0..iter_num {
+        iter += 1;
+        let active: Vec<usize> = (0..n).filter(|i| gamma[*i] > 0.).collect();
+
+        //sparsity factors S and quality factors Q
+        let (mut s_factor, mut q_factor) = (beta * &col_norms, beta * &mat_t_y);
+        if !active.is_empty() {
+            let mat_active =
+                columns_to_2darray(m, active.iter().map(|i| mat.column(*i).to_owned()))?;
+            let gamma_active = Array::from_iter(active.iter().map(|i| gamma[*i]));
+            let (cov, mu) = active_posterior(&mat_active, &gamma_active, beta, y)?;
+            let proj = mat.t().dot(&mat_active);
+            s_factor = s_factor - beta * beta * (&proj.dot(&cov) * &proj).sum_axis(Axis(1));
+            q_factor = q_factor - beta * proj.dot(&mu);
+
+            if noise_var.is_none() {
+                let well_determined: f64 = (0..active.len())
+                    .map(|k| 1. - cov[[k, k]] / gamma_active[k])
+                    .sum();
+                let residual = (y - &mat_active.dot(&mu)).norm_l2().powi(2);
+                beta = ((m as f64 - well_determined).max(F64_EPS) / residual).min(max_beta);
+            }
+            if let Prior::Laplace(None) = prior {
+                lambda = 2. * (active.len() as f64 - 1.) / gamma_active.sum();
+            }
+            log_ml.push(log_marginal_likelihood(&mat_active, &gamma_active, beta, y));
+        } else {
+            log_ml.push(log_marginal_likelihood(
+                &Array::zeros((m, 0)),
+                &Array::zeros(0),
+                beta,
+                y,
+            ));
+        }
+
+        //choose the update which increases the marginal likelihood the most
+        let mut best = None;
+        let mut best_gain = 0.;
+        for i in 0..n {
+            let (s, q) = if gamma[i] > 0. {
+                let scale = 1. - gamma[i] * s_factor[i];
+                (s_factor[i] / scale, q_factor[i] / scale)
+            } else {
+                (s_factor[i], q_factor[i])
+            };
+            if s <= 0. {
+                continue;
+            }
+            let new_gamma = optimal_gamma(s, q, lambda);
+            let gain = gamma_likelihood(s, q, lambda, new_gamma)
+                - gamma_likelihood(s, q, lambda, gamma[i]);
+            if gain > best_gain {
+                best_gain = gain;
+                best = Some((i, new_gamma));
+            }
+        }
+
+        match best {
+            Some((i, new_gamma)) if best_gain >= threshold => gamma[i] = new_gamma,
+            _ => break,
+        }
+    }
+
+    //final posterior
+    let active: Vec<usize> = (0..n).filter(|i| gamma[*i] > 0.).collect();
+    let mut mean = Array::zeros(n);
+    let mut cov = Array::zeros((n, n));
+    if !active.is_empty() {
+        let mat_active = columns_to_2darray(m, active.iter().map(|i| mat.column(*i).to_owned()))?;
+        let gamma_active = Array::from_iter(active.iter().map(|i| gamma[*i]));
+        let (cov_active, mu) = active_posterior(&mat_active, &gamma_active, beta, y)?;
+        for (k, i) in active.iter().enumerate() {
+            mean[*i] = mu[k];
+            for (l, j) in active.iter().enumerate() {
+                cov[[*i, *j]] = cov_active[[k, l]];
+            }
+        }
+    }
+
+    Ok(SblResult {
+        mean,
+        cov,
+        noise_precision: beta,
+        alpha: gamma.mapv(|g| if g > 0. { 1. / g } else { f64::INFINITY }),
+        log_marginal_likelihood: log_ml,
+        iter_num: iter,
+    })
+}
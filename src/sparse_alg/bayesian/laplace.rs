@@ -0,0 +1,77 @@
+//! # BCS Laplace
+//!
+//! Bayesian compressive sensing with laplace priors(Babacan et al.).
+//! Variances γ_i follow an exponential prior with rate λ/2,
+//! and they are updated by the fast marginal likelihood maximization.
+use super::super::SparseAlg;
+use super::fast_sbl::{fast_marginal_likelihood, Prior};
+use super::SblResult;
+use crate::prelude::*;
+
+pub struct BcsLaplace {
+    threshold: f64,
+    iter_num: usize,
+    lambda: Option<f64>,
+    noise_var: Option<f64>,
+}
+
+impl BcsLaplace {
+    #[allow(dead_code)]
+    pub fn new(threshold: f64, iter_num: usize) -> Self {
+        Self {
+            threshold,
+            iter_num,
+            lambda: None,
+            noise_var: None,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, threshold: f64, iter_num: usize) {
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+    }
+    ///Fix the rate of the laplace prior(None: estimate it)
+    #[allow(dead_code)]
+    pub fn set_lambda(&mut self, lambda: Option<f64>) {
+        self.lambda = lambda;
+    }
+    ///Fix the noise variance(None: estimate it)
+    #[allow(dead_code)]
+    pub fn set_noise_var(&mut self, noise_var: Option<f64>) {
+        self.noise_var = noise_var;
+    }
+    ///Solve and return the whole posterior
+    pub fn solve_posterior(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<SblResult> {
+        if let Some(lambda) = self.lambda {
+            if lambda < 0. {
+                return Err(anyhow!(format!(
+                    "lambda is {}, it is needed to be non-negative",
+                    lambda
+                )));
+            }
+        }
+        fast_marginal_likelihood(
+            mat,
+            y,
+            Prior::Laplace(self.lambda),
+            self.noise_var,
+            self.threshold,
+            self.iter_num,
+        )
+    }
+}
+
+impl SparseAlg for BcsLaplace {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_posterior(mat, y)?.mean)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        let result = self.solve_posterior(mat, y)?;
+        Ok((result.mean, Some(result.iter_num)))
+    }
+}
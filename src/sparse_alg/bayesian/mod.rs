@@ -1,5 +1,90 @@
 //! Bayesian Compressive Sensing
 //! 
+pub mod fast_sbl;
+pub mod laplace;
 pub mod rvm;
 #[cfg(test)]
-mod tests;
\ No newline at end of file
+mod tests;
+
+use crate::prelude::*;
+use ndarray_linalg::{Determinant, Solve};
+
+///Posterior of sparse bayesian learning.
+///FastSbl and BcsLaplace prune coefficients from the model, which have alpha = ∞ and zero mean/covariance.
+///Rvm keeps all coefficients, so irrelevant ones only have large alpha.
+pub struct SblResult {
+    ///posterior mean of x
+    pub mean: Array1<f64>,
+    ///posterior covariance of x
+    pub cov: Array2<f64>,
+    ///estimated noise precision(1 / noise variance)
+    pub noise_precision: f64,
+    ///precision of the prior of each coefficient
+    pub alpha: Array1<f64>,
+    ///log marginal likelihood ln p(y) at each iteration(NaN if it can't be computed)
+    pub log_marginal_likelihood: Vec<f64>,
+    pub iter_num: usize,
+}
+
+impl SblResult {
+    ///Posterior standard deviation of each coefficient
+    #[allow(dead_code)]
+    pub fn std_dev(&self) -> Array1<f64> {
+        self.cov.diag().mapv(|v| v.max(0.).sqrt())
+    }
+    ///Credible intervals mean ± z * std_dev (z = 1.96 gives 95% intervals).
+    ///Returns (lower bounds, upper bounds).
+    #[allow(dead_code)]
+    pub fn credible_intervals(&self, z: f64) -> (Array1<f64>, Array1<f64>) {
+        let width = z * self.std_dev();
+        (&self.mean - &width, &self.mean + &width)
+    }
+    ///Estimated noise variance
+    #[allow(dead_code)]
+    pub fn noise_var(&self) -> f64 {
+        1. / self.noise_precision
+    }
+    ///Predictive distribution of y for new rows of A.
+    ///Returns (predictive mean, predictive variance) of each row.
+    #[allow(dead_code)]
+    pub fn predict(&self, rows: &Array2<f64>) -> Result<(Array1<f64>, Array1<f64>)> {
+        if rows.ncols() != self.mean.len() {
+            return Err(anyhow!(format!(
+                "rows have {} columns, it is needed to be {}",
+                rows.ncols(),
+                self.mean.len()
+            )));
+        }
+        let mean = rows.dot(&self.mean);
+        let var = (&rows.dot(&self.cov) * rows).sum_axis(Axis(1)) + self.noise_var();
+        Ok((mean, var))
+    }
+    ///Indices of coefficients remaining in the model
+    #[allow(dead_code)]
+    pub fn relevant(&self) -> Vec<usize> {
+        (0..self.alpha.len())
+            .filter(|i| self.alpha[*i].is_finite())
+            .collect()
+    }
+}
+
+///ln p(y) = -(1/2)(m ln 2π + ln|C| + y^T C^(-1) y), C = (1/β)I + A Γ A^T.
+///It is only recorded, so NaN is returned if C can't be factorized(e.g. near-singular C of noiseless y).
+pub(super) fn log_marginal_likelihood(
+    mat: &Array2<f64>,
+    gamma: &Array1<f64>,
+    beta: f64,
+    y: &Array1<f64>,
+) -> f64 {
+    let m = mat.nrows();
+    let mut c = (mat * &gamma.view().insert_axis(Axis(0))).dot(&mat.t());
+    for i in 0..m {
+        c[[i, i]] += 1. / beta;
+    }
+    match (c.sln_det(), c.solve(y)) {
+        (Ok((_, ln_det)), Ok(c_inv_y)) => {
+            -0.5 * (m as f64 * (2. * PI).ln() + ln_det + y.dot(&c_inv_y))
+        }
+        _ => f64::NAN,
+    }
+}
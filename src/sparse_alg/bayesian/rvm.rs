@@ -1,4 +1,5 @@
 use super::super::SparseAlg;
+use super::{log_marginal_likelihood, SblResult};
 use crate::prelude::*;
 
 pub struct Rvm {
@@ -19,10 +20,8 @@ impl Rvm {
         self.threshold = threshold;
         self.iter_num = iter_num;
     }
-}
-
-impl SparseAlg for Rvm {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+    ///Solve and return the whole posterior(covariance, noise precision a[0] and so on)
+    pub fn solve_posterior(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<SblResult> {
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
@@ -41,9 +40,12 @@ impl SparseAlg for Rvm {
             mat.shape()[1],
             |i| 1. - a[i+1] * cov[[i, i]],
         );
+        let mut log_ml = Vec::new();
 
         //estimate hyperparameters
+        let mut iter = 0;
         for _ in 0..self.iter_num {
+            iter += 1;
             prev_a = a.clone();
             a[0] = (mat.shape()[0] as f64 - gamma.sum()) / (y - mat.dot(&mu)).norm_l2().powf(2.);
             for i in 0..mat.shape()[1] {
@@ -60,11 +62,40 @@ impl SparseAlg for Rvm {
                 |i| 1. - a[i+1] * cov[[i, i]],
             );
 
+            log_ml.push(log_marginal_likelihood(
+                mat,
+                &a.slice(s![1..]).mapv(|v| 1. / v),
+                a[0],
+                y,
+            ));
+
             if (&a - &prev_a).norm_l2() < self.threshold {
                 break;
             }
         }
 
-        Ok(mu)
+        Ok(SblResult {
+            mean: mu,
+            cov,
+            noise_precision: a[0],
+            alpha: a.slice(s![1..]).to_owned(),
+            log_marginal_likelihood: log_ml,
+            iter_num: iter,
+        })
+    }
+}
+
+impl SparseAlg for Rvm {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        let result = self.solve_posterior(mat, y)?;
+        Ok((result.mean, Some(result.iter_num)))
     }
 }
\ No newline at end of file
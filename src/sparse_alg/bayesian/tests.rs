@@ -4,10 +4,10 @@ pub use crate::prelude::*;
 fn bayesian_1sample_test() {
     std::env::set_var("RUST_BACKTRACE", "1");
 
-    let mut rng = rand::thread_rng();
+    let mut rng = test_rng();
     let input_data: Array1<f64> =
         rand_pulses_signal(&mut rng, 50, 2, 1.0, 2.0).expect("can't generate signal");
-    let matrix: Array2<f64> = ArrayBase::from_shape_fn((30, 50), |_| rng.gen_range(-1.0..1.0));
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
     let matrix = normalize_columns(&matrix).unwrap();
     let output_data = matrix.dot(&input_data);
 
@@ -51,4 +51,65 @@ fn bayesian_1sample_test() {
         &GREEN,
     );
     chart.draw_series(point_series).unwrap();
-}
\ No newline at end of file
+}
+#[test]
+fn fast_sbl_1sample_test() {
+    let mut rng = test_rng();
+    let input_data: Array1<f64> =
+        rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate signal");
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    let matrix = normalize_columns(&matrix).unwrap();
+    let output_data = matrix.dot(&input_data);
+
+    let fast_sbl = FastSbl::new(1e-8, 1000);
+    let bcs_laplace = BcsLaplace::new(1e-8, 1000);
+    for (name, result) in [
+        (
+            "fast_sbl",
+            fast_sbl.solve_posterior(&matrix, &output_data).unwrap(),
+        ),
+        (
+            "bcs_laplace",
+            bcs_laplace.solve_posterior(&matrix, &output_data).unwrap(),
+        ),
+    ] {
+        let rel_err = l2_relative_err(&input_data, &result.mean).unwrap();
+        println!(
+            "{}|| l2_relative_err: {}, relevant: {:?}, iter: {}",
+            name,
+            rel_err,
+            result.relevant(),
+            result.iter_num
+        );
+        assert!(rel_err < 1e-3);
+        assert_eq!(support(&result.mean, 1e-2), support(&input_data, 1e-2));
+        assert_eq!(result.log_marginal_likelihood.len(), result.iter_num);
+        let (lower, upper) = result.credible_intervals(1.96);
+        assert!(lower.iter().zip(upper.iter()).all(|(l, u)| l <= u));
+    }
+}
+
+#[test]
+fn rvm_posterior_test() {
+    let mut rng = test_rng();
+    let input_data: Array1<f64> =
+        rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate signal");
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    let matrix = normalize_columns(&matrix).unwrap();
+    let output_data = noisy_measurement(&mut rng, &matrix, &input_data, 30.).unwrap();
+
+    let rvm = Rvm::new(1e-5, 1000);
+    let result = rvm.solve_posterior(&matrix, &output_data).unwrap();
+    println!(
+        "noise_var: {}, log_marginal_likelihood: {:?}",
+        result.noise_var(),
+        result.log_marginal_likelihood.last()
+    );
+    assert_eq!(result.log_marginal_likelihood.len(), result.iter_num);
+    assert!(result.noise_var() > 0.);
+    assert!(result.std_dev().iter().all(|v| v.is_finite()));
+
+    let (mean, var) = result.predict(&matrix).unwrap();
+    assert_eq!(mean.len(), output_data.len());
+    assert!(var.iter().all(|v| *v >= result.noise_var()));
+}
@@ -0,0 +1,62 @@
+//! # Basis Pursuit
+//!
+//! Exact basis pursuit(minimize ||x||_1 s.t. Ax = y) by linear programming.
+//! x = u - v(u, v >= 0) and minimize 1^T(u + v) s.t. [A, -A][u; v] = y.
+use super::L1Relaxzation;
+use crate::lp::interior_point;
+use crate::prelude::*;
+
+pub struct L1BasisPursuit {
+    threshold: f64,
+    iter_num: usize,
+    by_bp: bool,
+}
+
+impl L1BasisPursuit {
+    #[allow(dead_code)]
+    pub fn new(threshold: f64, iter_num: usize, by_bp: bool) -> Self {
+        Self {
+            threshold,
+            iter_num,
+            by_bp,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, threshold: f64, iter_num: usize, by_bp: bool) {
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+        self.by_bp = by_bp;
+    }
+}
+
+impl L1Relaxzation for L1BasisPursuit {
+    fn solve_l1(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_l1_with_iter(mat, y)?.0)
+    }
+
+    fn solve_l1_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        is_underestimated_sys(mat, y)?;
+
+        let n = mat.ncols();
+        let mut lp_mat: Array2<f64> = Array::zeros((mat.nrows(), 2 * n));
+        lp_mat.slice_mut(s![.., ..n]).assign(mat);
+        lp_mat.slice_mut(s![.., n..]).assign(&(-mat));
+        let (uv, iter) = interior_point(
+            &Array::ones(2 * n),
+            &lp_mat,
+            y,
+            self.iter_num,
+            self.threshold,
+        )?;
+
+        Ok((&uv.slice(s![..n]) - &uv.slice(s![n..]), Some(iter)))
+    }
+
+    fn by_basis_pursuit(&self) -> bool {
+        self.by_bp
+    }
+}
@@ -33,4 +33,11 @@ impl L1Relaxzation for SparseAlgLasso {
     fn by_basis_pursuit(&self) -> bool {
         self.by_bp
     }
+    fn solve_l1_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        self.lasso_alg.solve_with_iter(mat, y, self.bs_lasso_lambda)
+    }
 }
@@ -0,0 +1,101 @@
+//! # Dantzig Selector
+//!
+//! minimize ||x||_1 s.t. ||A^T(y - Ax)||_inf <= δ (Candes & Tao) by linear programming.
+//! With G = A^T A, b = A^T y and x = u - v, it is
+//! minimize 1^T(u + v) s.t. G(u - v) + s_1 = b + δ, -G(u - v) + s_2 = δ - b, u, v, s_1, s_2 >= 0.
+use super::super::SparseAlg;
+use crate::lp::interior_point;
+use crate::prelude::*;
+
+///How δ of the Dantzig selector is given.
+#[derive(Clone, Copy, Debug)]
+pub enum DantzigDelta {
+    Fixed(f64),
+    ///δ = σ sqrt(2 ln n) max_j ||a_j||_2 from an estimate σ of the noise standard deviation.
+    NoiseStd(f64),
+}
+
+pub struct DantzigSelector {
+    delta: DantzigDelta,
+    threshold: f64,
+    iter_num: usize,
+}
+
+impl DantzigSelector {
+    #[allow(dead_code)]
+    pub fn new(delta: DantzigDelta, threshold: f64, iter_num: usize) -> Self {
+        Self {
+            delta,
+            threshold,
+            iter_num,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, delta: DantzigDelta, threshold: f64, iter_num: usize) {
+        self.delta = delta;
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+    }
+    ///δ used for mat
+    pub fn delta(&self, mat: &Array2<f64>) -> Result<f64> {
+        let delta = match self.delta {
+            DantzigDelta::Fixed(delta) => delta,
+            DantzigDelta::NoiseStd(sigma) => {
+                let max_col_norm = mat
+                    .columns()
+                    .into_iter()
+                    .map(|col| col.norm_l2())
+                    .fold(0., f64::max);
+                sigma * (2. * (mat.ncols() as f64).ln()).sqrt() * max_col_norm
+            }
+        };
+        if delta < 0. {
+            return Err(anyhow!(format!(
+                "delta is {}, it is needed to be non-negative",
+                delta
+            )));
+        }
+        Ok(delta)
+    }
+}
+
+impl SparseAlg for DantzigSelector {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        is_underestimated_sys(mat, y)?;
+
+        let n = mat.ncols();
+        let delta = self.delta(mat)?;
+        let gram = mat.t().dot(mat);
+        let mat_t_y = mat.t().dot(y);
+
+        //variables [u; v; s_1; s_2]
+        let mut lp_mat: Array2<f64> = Array::zeros((2 * n, 4 * n));
+        lp_mat.slice_mut(s![..n, ..n]).assign(&gram);
+        lp_mat.slice_mut(s![..n, n..2 * n]).assign(&(-&gram));
+        lp_mat.slice_mut(s![n.., ..n]).assign(&(-&gram));
+        lp_mat.slice_mut(s![n.., n..2 * n]).assign(&gram);
+        for i in 0..2 * n {
+            lp_mat[[i, 2 * n + i]] = 1.;
+        }
+        let mut b: Array1<f64> = Array::zeros(2 * n);
+        b.slice_mut(s![..n]).assign(&mat_t_y.mapv(|v| v + delta));
+        b.slice_mut(s![n..]).assign(&mat_t_y.mapv(|v| delta - v));
+        let mut c: Array1<f64> = Array::zeros(4 * n);
+        c.slice_mut(s![..2 * n]).fill(1.);
+
+        let (solution, iter) = interior_point(&c, &lp_mat, &b, self.iter_num, self.threshold)?;
+
+        Ok((
+            &solution.slice(s![..n]) - &solution.slice(s![n..2 * n]),
+            Some(iter),
+        ))
+    }
+}
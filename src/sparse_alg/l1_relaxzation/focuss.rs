@@ -8,6 +8,10 @@ pub struct L1Focuss {
 }
 
 impl L1Focuss {
+    //LSQR's maximum iterations are LSQR_ITER_RATE times the row size of mat
+    const LSQR_ITER_RATE: usize = 10;
+    const LSQR_THRESHOLD: f64 = 1e-12;
+
     #[allow(dead_code)]
     pub fn new(threshold: f64, iter_num: usize, by_bp: bool) -> Self {
         Self {
@@ -26,6 +30,14 @@ impl L1Focuss {
 
 impl L1Relaxzation for L1Focuss {
     fn solve_l1(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_l1_with_iter(mat, y)?.0)
+    }
+
+    fn solve_l1_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
@@ -37,17 +49,21 @@ impl L1Relaxzation for L1Focuss {
 
         let mut weights = x.clone();
 
+        let mut iter = 0;
         for _ in 0..self.iter_num {
+            iter += 1;
             prev_x = x;
-            let mut temp = mat.t().to_owned();
-            for i in 0..weights.shape()[0] {
-                for j in 0..temp.shape()[1] {
-                    temp[[i, j]] *= weights[i];
-                }
-            }
-
-            //update x
-            x = temp.dot(&pseudo_inverse(&mat.dot(&temp)).unwrap()).dot(y);
+            //update x = W A^T (A W A^T)^+ y = W^(1/2) (A W^(1/2))^+ y by LSQR
+            let sqrt_weights = weights.mapv(f64::sqrt);
+            let scaled_mat = mat * &sqrt_weights;
+            x = &sqrt_weights
+                * &lsqr(
+                    &scaled_mat,
+                    y,
+                    Self::LSQR_ITER_RATE * mat.nrows(),
+                    Self::LSQR_THRESHOLD,
+                )?
+                .x;
 
             //update weights
             for i in 0..x.shape()[0] {
@@ -62,7 +78,7 @@ impl L1Relaxzation for L1Focuss {
             }
         }
 
-        Ok(x)
+        Ok((x, Some(iter)))
     }
 
     fn by_basis_pursuit(&self) -> bool {
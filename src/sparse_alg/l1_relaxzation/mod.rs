@@ -2,8 +2,11 @@
 凸緩和により、スパースな解を求めるアルゴリズム。
 L0ノルムをLp(p = 0~1)など凸なものに緩和する。
 */
+pub mod basis_pursuit;
 pub mod by_lasso;
+pub mod dantzig;
 pub mod focuss;
+pub mod spgl1;
 #[cfg(test)]
 mod tests;
 
@@ -12,19 +15,35 @@ use crate::prelude::*;
 pub trait L1Relaxzation {
     fn solve_l1(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>>;
     fn by_basis_pursuit(&self) -> bool;
+    ///solve_l1 with the number of iterations(None if it is not iterative).
+    fn solve_l1_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        Ok((self.solve_l1(mat, y)?, None))
+    }
 }
 
 impl<T: L1Relaxzation> SparseAlg for T {
     //L1緩和では、Aのノルムが大きい列に対応する要素が非ゼロになりやすいバイアスがあるため、
     //スケーリングしたものを解とする。
     fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
         }
 
         if !self.by_basis_pursuit() {
-            return self.solve_l1(mat, y);
+            return self.solve_l1_with_iter(mat, y);
         }
 
         //calucalate mat's column sizes and normalize them
@@ -36,15 +55,13 @@ impl<T: L1Relaxzation> SparseAlg for T {
         let normalized_mat = normalize_columns(mat)?;
 
         //solve L1 minimization
-        let mut solution = self
-            .solve_l1(&normalized_mat, y)
-            .expect("failed to solve l1 minimization");
+        let (mut solution, iter) = self.solve_l1_with_iter(&normalized_mat, y)?;
 
         //scaling
         scaling.for_each(|(i, scale)| {
             solution[i] /= scale;
         });
 
-        Ok(solution)
+        Ok((solution, iter))
     }
 }
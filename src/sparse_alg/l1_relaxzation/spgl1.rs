@@ -0,0 +1,125 @@
+//! # SPGL1
+//!
+//! Basis pursuit denoising(minimize ||x||_1 s.t. ||Ax - y||_2 <= σ) by Pareto root finding
+//! (van den Berg & Friedlander). The Pareto curve φ(τ) = ||Ax_τ - y||_2 with
+//! x_τ = argmin ||Ax - y||_2 s.t. ||x||_1 <= τ is solved for φ(τ) = σ by Newton's method,
+//! using φ'(τ) = -||A^T r||_inf / ||r||_2. Each x_τ is calculated by accelerated projected gradient.
+use super::L1Relaxzation;
+use crate::prelude::*;
+
+pub struct L1Spgl1 {
+    sigma: f64,
+    threshold: f64,
+    iter_num: usize,
+    by_bp: bool,
+}
+
+impl L1Spgl1 {
+    const NEWTON_ITER_NUM: usize = 100;
+
+    #[allow(dead_code)]
+    pub fn new(sigma: f64, threshold: f64, iter_num: usize, by_bp: bool) -> Result<Self> {
+        if sigma < 0. {
+            return Err(anyhow!(format!(
+                "sigma is {}, it is needed to be non-negative",
+                sigma
+            )));
+        }
+        Ok(Self {
+            sigma,
+            threshold,
+            iter_num,
+            by_bp,
+        })
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, sigma: f64, threshold: f64, iter_num: usize, by_bp: bool) -> Result<()> {
+        if sigma < 0. {
+            return Err(anyhow!(format!(
+                "sigma is {}, it is needed to be non-negative",
+                sigma
+            )));
+        }
+        self.sigma = sigma;
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+        self.by_bp = by_bp;
+        Ok(())
+    }
+
+    ///minimize (1/2)||Ax - y||^(2) s.t. ||x||_1 <= tau from x, returning the number of iterations
+    fn solve_lasso_tau(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+        tau: f64,
+        x: &mut Array1<f64>,
+        lipshitz: f64,
+    ) -> usize {
+        let mut z = x.clone();
+        let mut beta: f64 = 1.;
+        let mut iter = 0;
+        for _ in 0..self.iter_num {
+            iter += 1;
+            let prev_x = x.clone();
+            let v = &z + &(mat.t().dot(&(y - &mat.dot(&z))) / lipshitz);
+            *x = project_l1_ball(&v, tau);
+            let prev_beta = beta;
+            beta = (1. + (1. + 4. * beta * beta).sqrt()) * 0.5;
+            z = &*x + &((prev_beta - 1.) / beta * (&*x - &prev_x));
+
+            //duality gap ||r||^2 - y^T r + tau ||A^T r||_inf
+            let r = y - &mat.dot(&*x);
+            let gap = r.dot(&r) - y.dot(&r) + tau * mat.t().dot(&r).norm_max();
+            if gap.abs() < self.threshold * y.dot(y).max(1.) {
+                break;
+            }
+        }
+        iter
+    }
+}
+
+impl L1Relaxzation for L1Spgl1 {
+    fn solve_l1(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_l1_with_iter(mat, y)?.0)
+    }
+
+    fn solve_l1_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        is_underestimated_sys(mat, y)?;
+
+        //initialization
+        let mut x: Array1<f64> = Array::zeros(mat.ncols());
+        if y.norm_l2() <= self.sigma {
+            return Ok((x, Some(0)));
+        }
+        let lipshitz = matrix_l2(mat).powi(2);
+        let mut tau = 0.;
+
+        let mut iter = 0;
+        for _ in 0..Self::NEWTON_ITER_NUM {
+            iter += self.solve_lasso_tau(mat, y, tau, &mut x, lipshitz);
+            let r = y - &mat.dot(&x);
+            let r_norm = r.norm_l2();
+            if (r_norm - self.sigma).abs() <= self.threshold.sqrt() * y.norm_l2().max(1.) {
+                break;
+            }
+
+            //Newton update of tau
+            let dual_norm = mat.t().dot(&r).norm_max();
+            if dual_norm <= F64_EPS {
+                break;
+            }
+            tau += (r_norm - self.sigma) * r_norm / dual_norm;
+        }
+
+        Ok((x, Some(iter)))
+    }
+
+    fn by_basis_pursuit(&self) -> bool {
+        self.by_bp
+    }
+}
@@ -11,7 +11,7 @@ fn l1_relax_1sample_test() {
 
     let focuss = L1Focuss::new(threshold, iter_num, true);
 
-    let mut rng = rand::thread_rng();
+    let mut rng = test_rng();
     let input_data: Array1<f64> = rand_pulses_signal(
         &mut rng,
         matrix_shape.1,
@@ -20,7 +20,7 @@ fn l1_relax_1sample_test() {
         pulse_value_range.1,
     )
     .expect("can't generate a signal");
-    let matrix: Array2<f64> = ArrayBase::from_shape_fn(matrix_shape, |_| rng.gen_range(-1.0..1.0));
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, matrix_shape, -1.0, 1.0);
     let output_data = matrix.dot(&input_data);
 
     let focuss_result = focuss.solve(&matrix, &output_data).unwrap();
@@ -109,7 +109,7 @@ fn l1_relax_average_test() {
     let mut l2_err_list = Vec::<(usize, [f64; 3])>::new();
 
     println!("calucalating omp and l1 relaxzation...");
-    let mut rng = rand::thread_rng();
+    let mut rng = test_rng();
     for support_size in supp_sizes_range.clone() {
         println!(
             "signals, whose support sizes are {}, are generated and test mps",
@@ -121,8 +121,7 @@ fn l1_relax_average_test() {
             if it % 100 == 0 {
                 println!("support size {}/sample num {}", support_size, it);
             }
-            let matrix: Array2<f64> =
-                ArrayBase::from_shape_fn(matrix_shape, |_| rng.gen_range(-1.0..1.0));
+            let matrix: Array2<f64> = rand_uniform_mat(&mut rng, matrix_shape, -1.0, 1.0);
             let matrix = normalize_columns(&matrix).expect("can't normalize matrix");
             let input_signal = rand_pulses_signal(
                 &mut rng,
@@ -268,3 +267,76 @@ fn l1_relax_average_test() {
     chart.draw_series(line_series).unwrap();
 }
  */
+
+#[test]
+fn basis_pursuit_test() {
+    let matrix_shape = (30, 60);
+    let mut rng = test_rng();
+    let input_data: Array1<f64> = rand_pulses_signal(&mut rng, matrix_shape.1, 5, 1.0, 2.0)
+        .expect("can't generate a signal");
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, matrix_shape, -1.0, 1.0);
+    let output_data = matrix.dot(&input_data);
+
+    let bp = L1BasisPursuit::new(1e-10, 100, false);
+    let (bp_result, bp_iter) = bp.solve_l1_with_iter(&matrix, &output_data).unwrap();
+    let spgl1 = L1Spgl1::new(0., 1e-12, 10000, false).unwrap();
+    let spgl1_result = spgl1.solve_l1(&matrix, &output_data).unwrap();
+    println!(
+        "l2_relative_err|| bp: {}(iter: {:?}), spgl1: {}",
+        l2_relative_err(&input_data, &bp_result).unwrap(),
+        bp_iter,
+        l2_relative_err(&input_data, &spgl1_result).unwrap(),
+    );
+    assert!((&output_data - &matrix.dot(&bp_result)).norm_l2() < 1e-6);
+    assert!(l2_relative_err(&input_data, &bp_result).unwrap() < 1e-6);
+    assert!(l2_relative_err(&input_data, &spgl1_result).unwrap() < 1e-6);
+
+    //basis pursuit denoising: the residual reaches sigma
+    let sigma = 0.5;
+    let noisy = noisy_measurement(&mut rng, &matrix, &input_data, 20.).unwrap();
+    let bpdn = L1Spgl1::new(sigma, 1e-12, 10000, false).unwrap();
+    let bpdn_result = bpdn.solve_l1(&matrix, &noisy).unwrap();
+    let residual = (&noisy - &matrix.dot(&bpdn_result)).norm_l2();
+    println!("bpdn|| residual: {}", residual);
+    assert!((residual - sigma).abs() < 1e-4);
+}
+
+#[test]
+fn dantzig_selector_test() {
+    let matrix_shape = (30, 60);
+    //the errors are compared on a fixed problem
+    let mut rng = SeededRng::new(0);
+    let input_data: Array1<f64> = rand_pulses_signal(&mut rng, matrix_shape.1, 4, 1.0, 2.0)
+        .expect("can't generate a signal");
+    let matrix: Array2<f64> = rand_gaussian_mat(&mut rng, matrix_shape, 1.);
+    let matrix = normalize_columns(&matrix).unwrap();
+    let clean = matrix.dot(&input_data);
+    let noise_std = 0.02;
+    let noise: Array1<f64> = Array::from_shape_fn(matrix_shape.0, |_| {
+        noise_std * rng.sample::<f64, _>(StandardNormal)
+    });
+    let output_data = &clean + &noise;
+
+    let dantzig = DantzigSelector::new(DantzigDelta::NoiseStd(noise_std), 1e-9, 100);
+    let dantzig_result = dantzig.solve(&matrix, &output_data).unwrap();
+    let delta = dantzig.delta(&matrix).unwrap();
+    let lasso = SparseAlgLasso::new(delta, Box::new(LassoFista::new(10000, 1e-12)), false);
+    let lasso_result = lasso.solve(&matrix, &output_data).unwrap();
+
+    let dantzig_err = l2_relative_err(&input_data, &dantzig_result).unwrap();
+    let lasso_err = l2_relative_err(&input_data, &lasso_result).unwrap();
+    println!("l2_relative_err|| dantzig: {}, lasso: {}", dantzig_err, lasso_err);
+    //the constraint is satisfied
+    let correlation = matrix.t().dot(&(&output_data - &matrix.dot(&dantzig_result)));
+    assert!(correlation.norm_max() <= delta * (1. + 1e-6));
+    assert!(dantzig_err < 0.2);
+    assert!(dantzig_err < 3. * lasso_err);
+    assert_eq!(support(&dantzig_result, 0.1), support(&input_data, 0.1));
+
+    //small δ gives the basis pursuit solution for noiseless data.
+    //much smaller δ makes the normal equations of the LP singular.
+    let exact = DantzigSelector::new(DantzigDelta::Fixed(1e-6), 1e-10, 100)
+        .solve(&matrix, &clean)
+        .unwrap();
+    assert!(l2_relative_err(&input_data, &exact).unwrap() < 1e-4);
+}
@@ -1,5 +1,6 @@
 /* matching pursuit */
 pub mod mp;
+pub mod nnomp;
 pub mod omp;
 #[cfg(test)]
 mod tests;
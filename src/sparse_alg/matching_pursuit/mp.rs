@@ -19,26 +19,30 @@ impl Mp {
         self.threshold = threshold;
         self.iter_num = iter_num;
     }
-}
 
-impl SparseAlg for Mp {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+    fn solve_op_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
         }
 
         //initialization
-        let mat_normalized = normalize_columns(mat).unwrap();
-        let mut x: Array1<f64> = Array::zeros(mat.shape()[1]);
+        let column_norms = mat
+            .column_norms()
+            .mapv(|l2_norm| if l2_norm == 0.0 { 1. } else { l2_norm });
+        let mut x: Array1<f64> = Array::zeros(mat.shape().1);
         let mut r = y.clone();
         let mut support = HashSet::new();
 
+        let mut iter = 0;
         for _ in 0..self.iter_num {
+            iter += 1;
             //rの射影が最大となる列探索
-            let (target_idx, _) = mat_normalized
-                .t()
-                .dot(&r)
+            let (target_idx, _) = (mat.t_mul_vec(&r) / &column_norms)
                 .iter()
                 .map(|v| v.abs())
                 .enumerate()
@@ -49,7 +53,7 @@ impl SparseAlg for Mp {
             support.insert(target_idx);
 
             //update tentative solution(x)
-            let target_col = mat.slice(s![.., target_idx]).to_owned();
+            let target_col = mat.column_vec(target_idx);
             let temp = target_col.t().dot(&r) / target_col.norm_l2().powf(2.0);
             x[target_idx] += temp;
 
@@ -61,6 +65,24 @@ impl SparseAlg for Mp {
             }
         }
 
-        Ok(x)
+        Ok((x, Some(iter)))
+    }
+}
+
+impl SparseAlg for Mp {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_op_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        self.solve_op_with_iter(mat, y)
+    }
+
+    fn solve_op(&self, mat: &dyn LinearOperator, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_op_with_iter(mat, y)?.0)
     }
 }
@@ -0,0 +1,77 @@
+//! # NNOMP
+//!
+//! Non-negative orthogonal matching pursuit.
+//! Only columns positively correlated with the residual are selected,
+//! and the tentative solution is refitted by non-negative least squares.
+use super::super::SparseAlg;
+use crate::prelude::*;
+
+pub struct NnOmp {
+    threshold: f64,
+    iter_num: usize,
+}
+
+impl NnOmp {
+    #[allow(dead_code)]
+    pub fn new(threshold: f64, iter_num: usize) -> Self {
+        Self {
+            threshold,
+            iter_num,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, threshold: f64, iter_num: usize) {
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+    }
+}
+
+impl SparseAlg for NnOmp {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        is_underestimated_sys(mat, y)?;
+
+        //initialization
+        let column_norms = mat
+            .column_norms()
+            .mapv(|l2_norm| if l2_norm == 0.0 { 1. } else { l2_norm });
+        let mut x: Array1<f64> = Array::zeros(mat.ncols());
+        let mut r = y.clone();
+        let mut support = HashSet::new();
+
+        let mut iter = 0;
+        for _ in 0..cmp::min(mat.ncols(), self.iter_num) {
+            //rとの相関が正で最大となる列探索
+            let target = (mat.t().dot(&r) / &column_norms)
+                .iter()
+                .copied()
+                .enumerate()
+                .filter(|(i, _)| !support.contains(i))
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            match target {
+                Some((i, corr)) if corr > F64_EPS => support.insert(i),
+                _ => break,
+            };
+            iter += 1;
+
+            //update tentative solution(x) by NNLS
+            x = nnls_with_support(mat, y, &support)?;
+
+            //update residual(r)
+            r = y - &mat.dot(&x);
+
+            if r.norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        Ok((x, Some(iter)))
+    }
+}
@@ -16,26 +16,30 @@ impl Omp {
         self.threshold = threshold;
         self.iter_num = iter_num;
     }
-}
 
-impl SparseAlg for Omp {
-    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+    fn solve_op_with_iter(
+        &self,
+        mat: &dyn LinearOperator,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
         }
 
         //initialization
-        let mat_normalized = normalize_columns(mat).unwrap();
-        let mut x: Array1<f64> = Array::zeros(mat.shape()[1]);
+        let column_norms = mat
+            .column_norms()
+            .mapv(|l2_norm| if l2_norm == 0.0 { 1. } else { l2_norm });
+        let mut x: Array1<f64> = Array::zeros(mat.shape().1);
         let mut r = y.clone();
         let mut support = HashSet::new();
 
-        for _ in 0..std::cmp::min(mat.shape()[1], self.iter_num) {
+        let mut iter = 0;
+        for _ in 0..std::cmp::min(mat.shape().1, self.iter_num) {
+            iter += 1;
             //rの射影が最大となる列探索
-            let (target_idx, _) = mat_normalized
-                .t()
-                .dot(&r)
+            let (target_idx, _) = (mat.t_mul_vec(&r) / &column_norms)
                 .iter()
                 .map(|v| v.abs())
                 .enumerate()
@@ -50,13 +54,31 @@ impl SparseAlg for Omp {
             x = lsm_with_support(mat, y, &support).expect("Can't solve lsm");
 
             //update residual(r)
-            r = y - mat.dot(&x);
+            r = y - mat.mul_vec(&x);
 
             if r.norm_l2() < self.threshold {
                 break;
             }
         }
 
-        Ok(x)
+        Ok((x, Some(iter)))
+    }
+}
+
+impl SparseAlg for Omp {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_op_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        self.solve_op_with_iter(mat, y)
+    }
+
+    fn solve_op(&self, mat: &dyn LinearOperator, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_op_with_iter(mat, y)?.0)
     }
 }
@@ -4,10 +4,10 @@ use crate::prelude::*;
 fn mp_1sample_test() {
     std::env::set_var("RUST_BACKTRACE", "1");
 
-    let mut rng = rand::thread_rng();
+    let mut rng = test_rng();
     let input_data: Array1<f64> =
         rand_pulses_signal(&mut rng, 50, 10, 1.0, 2.0).expect("can't generate signal");
-    let matrix: Array2<f64> = ArrayBase::from_shape_fn((30, 50), |_| rng.gen_range(-1.0..1.0));
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
     let matrix = normalize_columns(&matrix).unwrap();
     let output_data = matrix.dot(&input_data);
 
@@ -117,7 +117,7 @@ fn mp_average_test() {
     let mut l2_err_list = Vec::<(usize, [f64; 4])>::new();
 
     println!("calucalating mps...");
-    let mut rng = rand::thread_rng();
+    let mut rng = test_rng();
     for support_size in supp_sizes_range.clone() {
         println!(
             "signals, whose support sizes are {}, are generated and test mps",
@@ -129,8 +129,7 @@ fn mp_average_test() {
             if it % 100 == 0 {
                 println!("support size {}/sample num {}", support_size, it);
             }
-            let matrix: Array2<f64> =
-                ArrayBase::from_shape_fn(matrix_shape, |_| rng.gen_range(-1.0..1.0));
+            let matrix: Array2<f64> = rand_uniform_mat(&mut rng, matrix_shape, -1.0, 1.0);
             let matrix = normalize_columns(&matrix).expect("can't normalize matrix");
             let input_signal = rand_pulses_signal(
                 &mut rng,
@@ -297,3 +296,48 @@ fn mp_average_test() {
     );
     chart.draw_series(line_series).unwrap();
 }
+
+#[test]
+fn nnomp_1sample_test() {
+    let mut rng = test_rng();
+    //non-negative signal
+    let input_data: Array1<f64> = rand_pulses_signal(&mut rng, 50, 4, 1.0, 2.0)
+        .expect("can't generate a signal")
+        .mapv(f64::abs);
+    let matrix: Array2<f64> = rand_uniform_mat(&mut rng, (30, 50), -1.0, 1.0);
+    let output_data = matrix.dot(&input_data);
+
+    let nnomp = NnOmp::new(1e-8, 50);
+    let nnomp_result = nnomp.solve(&matrix, &output_data).unwrap();
+    println!(
+        "l2_relative_err|| nnomp: {}",
+        l2_relative_err(&input_data, &nnomp_result).unwrap()
+    );
+    assert!(nnomp_result.iter().all(|v| *v >= 0.));
+    assert!(l2_relative_err(&input_data, &nnomp_result).unwrap() < 1e-6);
+
+    //nnls on the whole matrix is non-negative and optimal(KKT conditions)
+    let nnls_result = nnls(&matrix, &output_data).unwrap();
+    let grad = matrix.t().dot(&(&output_data - &matrix.dot(&nnls_result)));
+    assert!(nnls_result.iter().all(|v| *v >= 0.));
+    assert!(grad.iter().all(|g| *g <= 1e-8));
+    assert!(nnls_result
+        .iter()
+        .zip(grad.iter())
+        .all(|(x, g)| *x == 0. || g.abs() <= 1e-8));
+}
+
+#[test]
+fn nnls_degenerate_test() {
+    //duplicated and zero columns
+    let matrix = array![[1., 1., 0., 0.], [0., 0., 1., 0.]];
+    let output_data = array![2., -1.];
+    let nnls_result = nnls(&matrix, &output_data).unwrap();
+    assert!(nnls_result.iter().all(|v| *v >= 0.));
+    assert!((matrix.dot(&nnls_result) - array![2., 0.]).norm_l2() < 1e-12);
+    assert_eq!(nnls_result[2], 0.);
+
+    let support = [0, 2, 3].into_iter().collect();
+    let nnls_result = nnls_with_support(&matrix, &output_data, &support).unwrap();
+    assert!((nnls_result - array![2., 0., 0., 0.]).norm_l2() < 1e-12);
+}
@@ -38,6 +38,14 @@ impl Wmp {
 
 impl SparseAlg for Wmp {
     fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
         match is_underestimated_sys(mat, y) {
             Err(msg) => return Err(msg),
             Ok(_) => (),
@@ -48,7 +56,9 @@ impl SparseAlg for Wmp {
         let mut r = y.clone();
         let mut support = HashSet::new();
 
+        let mut iter = 0;
         for _ in 0..self.iter_num {
+            iter += 1;
             //rの射影が最初に閾値を超える列を探す
             let mut target_idx = 0;
             let mut max_proj = 0.;
@@ -81,6 +91,6 @@ impl SparseAlg for Wmp {
             }
         }
 
-        Ok(x)
+        Ok((x, Some(iter)))
     }
 }
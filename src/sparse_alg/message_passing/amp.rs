@@ -0,0 +1,121 @@
+//! # AMP
+//!
+//! Approximate message passing with soft thresholding(Donoho, Maleki & Montanari).
+//! The columns of A are supposed to be i.i.d. N(0, 1/m) like(nearly unit l2 norm).
+//! The threshold at each iteration is alpha * τ_t, τ_t = ||z_t||_2 / sqrt(m).
+use super::super::SparseAlg;
+use crate::prelude::*;
+
+pub struct Amp {
+    alpha: f64,
+    threshold: f64,
+    iter_num: usize,
+}
+
+impl Amp {
+    //grid of the gaussian quadrature in state evolution
+    const GRID_NUM: usize = 201;
+    const GRID_RANGE: f64 = 8.;
+
+    #[allow(dead_code)]
+    pub fn new(alpha: f64, threshold: f64, iter_num: usize) -> Self {
+        Self {
+            alpha,
+            threshold,
+            iter_num,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, alpha: f64, threshold: f64, iter_num: usize) {
+        self.alpha = alpha;
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+    }
+
+    ///Estimates x_1, x_2, ... at each iteration
+    pub fn solve_iterates(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Vec<Array1<f64>>> {
+        is_underestimated_sys(mat, y)?;
+
+        //initialization
+        let (m, n) = (mat.nrows(), mat.ncols());
+        let mut x: Array1<f64> = Array::zeros(n);
+        let mut z = y.clone();
+        let mut iterates = Vec::new();
+
+        for _ in 0..self.iter_num {
+            let prev_x = x.clone();
+            let tau = z.norm_l2() / (m as f64).sqrt();
+            let v = &x + &mat.t().dot(&z);
+            x = st_array1(self.alpha * tau, &v);
+
+            //residual with Onsager correction (1/δ)<η'>z
+            let active_num = x.iter().filter(|v| **v != 0.).count();
+            z = y - &mat.dot(&x) + &(active_num as f64 / m as f64 * &z);
+            iterates.push(x.clone());
+
+            if (&x - &prev_x).norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        Ok(iterates)
+    }
+
+    ///State evolution prediction of MSE ||x_t - x||^2 / n for t = 1, 2, ..., iter_num.
+    ///x is the signal(or samples of its distribution), delta = m / n and
+    ///noise_var is the variance of each element of the noise.
+    pub fn state_evolution(&self, x: &Array1<f64>, delta: f64, noise_var: f64) -> Result<Vec<f64>> {
+        if delta <= 0. || x.is_empty() {
+            return Err(anyhow!(format!(
+                "delta is {} and x has {} elements, they are needed to be positive",
+                delta,
+                x.len()
+            )));
+        }
+
+        //gaussian quadrature by a fine grid
+        let step = 2. * Self::GRID_RANGE / (Self::GRID_NUM - 1) as f64;
+        let grid: Vec<(f64, f64)> = (0..Self::GRID_NUM)
+            .map(|i| {
+                let g = -Self::GRID_RANGE + step * i as f64;
+                (g, (-0.5 * g * g).exp() / (2. * PI).sqrt() * step)
+            })
+            .collect();
+
+        let mut tau2 = noise_var + x.dot(x) / x.len() as f64 / delta;
+        let mut mse_trace = Vec::new();
+        for _ in 0..self.iter_num {
+            let tau = tau2.sqrt();
+            let mse = x
+                .iter()
+                .map(|x_i| {
+                    grid.iter()
+                        .map(|(g, w)| w * (st(self.alpha * tau, x_i + tau * g) - x_i).powi(2))
+                        .sum::<f64>()
+                })
+                .sum::<f64>()
+                / x.len() as f64;
+            mse_trace.push(mse);
+            tau2 = noise_var + mse / delta;
+        }
+
+        Ok(mse_trace)
+    }
+}
+
+impl SparseAlg for Amp {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        let mut iterates = self.solve_iterates(mat, y)?;
+        let iter = iterates.len();
+        let x = iterates.pop().unwrap_or_else(|| Array::zeros(mat.ncols()));
+        Ok((x, Some(iter)))
+    }
+}
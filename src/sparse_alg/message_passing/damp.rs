@@ -0,0 +1,67 @@
+//! # D-AMP
+//!
+//! Denoising-based approximate message passing(Metzler, Maleki & Baraniuk).
+//! The soft thresholding of AMP is replaced by a Denoiser, which is given the estimated
+//! noise level τ_t = ||z_t||_2 / sqrt(m), and the Onsager correction uses its divergence.
+use super::super::SparseAlg;
+use crate::prelude::*;
+
+pub struct Damp<D: Denoiser> {
+    denoiser: D,
+    threshold: f64,
+    iter_num: usize,
+}
+
+impl<D: Denoiser> Damp<D> {
+    #[allow(dead_code)]
+    pub fn new(denoiser: D, threshold: f64, iter_num: usize) -> Self {
+        Self {
+            denoiser,
+            threshold,
+            iter_num,
+        }
+    }
+    #[allow(dead_code)]
+    pub fn set(&mut self, threshold: f64, iter_num: usize) {
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+    }
+}
+
+impl<D: Denoiser> SparseAlg for Damp<D> {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        is_underestimated_sys(mat, y)?;
+
+        //initialization
+        let m = mat.nrows();
+        let mut x: Array1<f64> = Array::zeros(mat.ncols());
+        let mut z = y.clone();
+
+        let mut iter = 0;
+        for _ in 0..self.iter_num {
+            iter += 1;
+            let prev_x = x.clone();
+            let tau = z.norm_l2() / (m as f64).sqrt();
+            let v = &x + &mat.t().dot(&z);
+            x = self.denoiser.denoise(&v, tau);
+
+            //residual with Onsager correction
+            let divergence = self.denoiser.divergence(&v, tau);
+            z = y - &mat.dot(&x) + &(divergence / m as f64 * &z);
+
+            if (&x - &prev_x).norm_l2() < self.threshold {
+                break;
+            }
+        }
+
+        Ok((x, Some(iter)))
+    }
+}
@@ -0,0 +1,6 @@
+/* message passing */
+pub mod amp;
+pub mod damp;
+#[cfg(test)]
+mod tests;
+pub mod vamp;
@@ -0,0 +1,82 @@
+pub use crate::prelude::*;
+
+#[test]
+fn message_passing_1sample_test() {
+    let matrix_shape = (250, 500);
+    let mut rng = test_rng();
+    let input_data: Array1<f64> = rand_gaussian_sparse_signal(&mut rng, matrix_shape.1, 25, 1.0)
+        .expect("can't generate signal");
+    let matrix = rand_gaussian_mat(&mut rng, matrix_shape, 1. / (matrix_shape.0 as f64).sqrt());
+    let output_data = matrix.dot(&input_data);
+
+    let amp = Amp::new(1.5, 1e-10, 300);
+    let amp_result = amp.solve(&matrix, &output_data).unwrap();
+    let vamp = Vamp::new(1.5, 1e-10, 1e-10, 300).unwrap();
+    let vamp_result = vamp.solve(&matrix, &output_data).unwrap();
+
+    for (name, result) in [("amp", &amp_result), ("vamp", &vamp_result)] {
+        let rel_err = l2_relative_err(&input_data, result).unwrap();
+        println!("{}|| l2_relative_err: {}", name, rel_err);
+        assert!(rel_err < 1e-3);
+    }
+}
+
+#[test]
+fn amp_state_evolution_test() {
+    let matrix_shape = (500, 1000);
+    let mut rng = test_rng();
+    let input_data: Array1<f64> = rand_gaussian_sparse_signal(&mut rng, matrix_shape.1, 50, 1.0)
+        .expect("can't generate signal");
+    let matrix = rand_gaussian_mat(&mut rng, matrix_shape, 1. / (matrix_shape.0 as f64).sqrt());
+    let output_data = matrix.dot(&input_data);
+
+    let amp = Amp::new(1.5, 0., 10);
+    let iterates = amp.solve_iterates(&matrix, &output_data).unwrap();
+    let delta = matrix_shape.0 as f64 / matrix_shape.1 as f64;
+    let predicted = amp.state_evolution(&input_data, delta, 0.).unwrap();
+
+    for (x, mse) in iterates.iter().zip(predicted.iter()).take(5) {
+        let empirical = (x - &input_data).norm_l2().powi(2) / matrix_shape.1 as f64;
+        println!("empirical mse: {}, predicted mse: {}", empirical, mse);
+        assert!((empirical - mse).abs() < 0.3 * mse + 1e-6);
+    }
+}
+
+#[test]
+fn damp_1sample_test() {
+    let matrix_shape = (250, 500);
+    let mut rng = test_rng();
+    let input_data: Array1<f64> = rand_block_sparse_signal(&mut rng, matrix_shape.1, 5, 8, 1.0)
+        .expect("can't generate signal");
+    let matrix = rand_gaussian_mat(&mut rng, matrix_shape, 1. / (matrix_shape.0 as f64).sqrt());
+    let output_data = matrix.dot(&input_data);
+
+    //soft thresholding as the denoiser is AMP
+    let amp_result = Amp::new(1.5, 1e-10, 300)
+        .solve(&matrix, &output_data)
+        .unwrap();
+    let damp_soft_result = Damp::new(SoftThreshold::new(1.5), 1e-10, 300)
+        .solve(&matrix, &output_data)
+        .unwrap();
+    assert!((&amp_result - &damp_soft_result).norm_l2() < 1e-8);
+
+    let damp_block = Damp::new(BlockThreshold::new(5, 2.).unwrap(), 1e-10, 300);
+    let damp_block_result = damp_block.solve(&matrix, &output_data).unwrap();
+    let rel_err = l2_relative_err(&input_data, &damp_block_result).unwrap();
+    println!("damp(block threshold)|| l2_relative_err: {}", rel_err);
+    assert!(rel_err < 1e-3);
+}
+
+#[test]
+fn vamp_zero_measurement_test() {
+    let mut rng = test_rng();
+    let matrix = rand_gaussian_mat(&mut rng, (20, 40), 1.);
+    let output_data: Array1<f64> = Array::zeros(20);
+
+    let (result, iter) = Vamp::new(1.5, 1e-10, 1e-10, 300)
+        .unwrap()
+        .solve_with_iter(&matrix, &output_data)
+        .unwrap();
+    assert_eq!(result, Array1::<f64>::zeros(40));
+    assert_eq!(iter, Some(0));
+}
@@ -0,0 +1,121 @@
+//! # VAMP
+//!
+//! Vector approximate message passing with soft thresholding(Rangan, Schniter & Fletcher).
+//! The LMMSE step uses the SVD of A, so it is robust to ill-conditioned matrices.
+//! The threshold of the denoiser is alpha / sqrt(γ_1), γ_1 is the precision of r_1.
+use super::super::SparseAlg;
+use crate::prelude::*;
+
+pub struct Vamp {
+    alpha: f64,
+    noise_var: f64,
+    threshold: f64,
+    iter_num: usize,
+}
+
+impl Vamp {
+    #[allow(dead_code)]
+    pub fn new(alpha: f64, noise_var: f64, threshold: f64, iter_num: usize) -> Result<Self> {
+        if noise_var <= 0. {
+            return Err(anyhow!(format!(
+                "noise_var is {}, it is needed to be positive",
+                noise_var
+            )));
+        }
+        Ok(Self {
+            alpha,
+            noise_var,
+            threshold,
+            iter_num,
+        })
+    }
+    #[allow(dead_code)]
+    pub fn set(
+        &mut self,
+        alpha: f64,
+        noise_var: f64,
+        threshold: f64,
+        iter_num: usize,
+    ) -> Result<()> {
+        if noise_var <= 0. {
+            return Err(anyhow!(format!(
+                "noise_var is {}, it is needed to be positive",
+                noise_var
+            )));
+        }
+        self.alpha = alpha;
+        self.noise_var = noise_var;
+        self.threshold = threshold;
+        self.iter_num = iter_num;
+        Ok(())
+    }
+}
+
+impl SparseAlg for Vamp {
+    fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>> {
+        Ok(self.solve_with_iter(mat, y)?.0)
+    }
+
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        is_underestimated_sys(mat, y)?;
+        if y.norm_l2() == 0. {
+            return Ok((Array::zeros(mat.ncols()), Some(0)));
+        }
+
+        //A = U S V^T(only the nonzero singular values are used)
+        let n = mat.ncols();
+        let (u, s, vt) = mat
+            .svd(true, true)
+            .map_err(|_| anyhow!("can't compute SVD of the matrix"))?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let rank = s.iter().filter(|v| **v > F64_EPS).count();
+        let s = s.slice(s![..rank]).to_owned();
+        let vt = vt.slice(s![..rank, ..]).to_owned();
+        let s_uty = &s * &u.slice(s![.., ..rank]).t().dot(y);
+        let gamma_w = 1. / self.noise_var;
+
+        //initialization(r_2 = 0 with the precision of the prior power of x)
+        let mut r2: Array1<f64> = Array::zeros(n);
+        let mut gamma2 = (s.dot(&s) / y.dot(y)).max(F64_EPS);
+        let mut x1: Array1<f64> = Array::zeros(n);
+
+        let mut iter = 0;
+        for _ in 0..self.iter_num {
+            iter += 1;
+            let prev_x1 = x1.clone();
+
+            //LMMSE estimation
+            let d = s.mapv(|s_i| 1. / (gamma_w * s_i * s_i + gamma2));
+            let vt_r2 = vt.dot(&r2);
+            let x2 = &r2
+                + &vt
+                    .t()
+                    .dot(&(&d * &(gamma_w * &s_uty + gamma2 * &vt_r2) - &vt_r2));
+            let alpha2 = gamma2 * (d.sum() + (n - rank) as f64 / gamma2) / n as f64;
+            let eta2 = gamma2 / alpha2;
+            let gamma1 = eta2 - gamma2;
+            if gamma1 <= F64_EPS {
+                break;
+            }
+            let r1 = (eta2 * &x2 - gamma2 * &r2) / gamma1;
+
+            //denoising
+            x1 = st_array1(self.alpha / gamma1.sqrt(), &r1);
+            let active_num = cmp::max(x1.iter().filter(|v| **v != 0.).count(), 1);
+            let alpha1 = active_num as f64 / n as f64;
+            let eta1 = gamma1 / alpha1;
+            let next_gamma2 = eta1 - gamma1;
+            if (&x1 - &prev_x1).norm_l2() < self.threshold || next_gamma2 <= F64_EPS {
+                break;
+            }
+            r2 = (eta1 * &x1 - gamma1 * &r1) / next_gamma2;
+            gamma2 = next_gamma2;
+        }
+
+        Ok((x1, Some(iter)))
+    }
+}
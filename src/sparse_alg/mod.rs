@@ -4,13 +4,37 @@
 mod l1_relaxzation;
 mod matching_pursuit;
 mod bayesian;
+mod message_passing;
 
 use crate::prelude::*;
 
-pub use l1_relaxzation::{by_lasso::SparseAlgLasso, focuss::L1Focuss, L1Relaxzation};
-pub use matching_pursuit::{mp::Mp, omp::Omp, threshold_alg::ThresholdAlg, wmp::Wmp};
-pub use bayesian::rvm::Rvm;
+pub use l1_relaxzation::{
+    basis_pursuit::L1BasisPursuit,
+    by_lasso::SparseAlgLasso,
+    dantzig::{DantzigDelta, DantzigSelector},
+    focuss::L1Focuss,
+    spgl1::L1Spgl1,
+    L1Relaxzation,
+};
+pub use matching_pursuit::{
+    mp::Mp, nnomp::NnOmp, omp::Omp, threshold_alg::ThresholdAlg, wmp::Wmp,
+};
+pub use bayesian::{fast_sbl::FastSbl, laplace::BcsLaplace, rvm::Rvm, SblResult};
+pub use message_passing::{amp::Amp, damp::Damp, vamp::Vamp};
 
 pub trait SparseAlg {
     fn solve(&self, mat: &Array2<f64>, y: &Array1<f64>) -> Result<Array1<f64>>;
+    ///Solve and return the solution with the number of iterations(None if it is not iterative).
+    fn solve_with_iter(
+        &self,
+        mat: &Array2<f64>,
+        y: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Option<usize>)> {
+        Ok((self.solve(mat, y)?, None))
+    }
+    ///Solve with mat given as a LinearOperator(e.g. CsrMatrix).
+    ///Algorithms which need a dense matrix convert it by to_dense.
+    fn solve_op(&self, mat: &dyn LinearOperator, y: &Array1<f64>) -> Result<Array1<f64>> {
+        self.solve(&mat.to_dense(), y)
+    }
 }
@@ -0,0 +1,122 @@
+use super::{ColumnScale, Compressed, CsrMatrix, LinearOperator};
+use crate::prelude::*;
+
+///Compressed sparse column matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscMatrix {
+    inner: Compressed,
+}
+
+impl CscMatrix {
+    ///Column j's elements are data[indptr[j]..indptr[j+1]] at rows indices[indptr[j]..indptr[j+1]].
+    pub fn new(
+        shape: (usize, usize),
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<f64>,
+    ) -> Result<Self> {
+        Ok(Self {
+            inner: Compressed::new(shape.1, shape.0, indptr, indices, data)?,
+        })
+    }
+
+    ///Make from (row, column, value) entries. Duplicated entries are summed up.
+    pub fn from_triplets(shape: (usize, usize), triplets: &[(usize, usize, f64)]) -> Result<Self> {
+        Ok(Self {
+            inner: Compressed::from_entries(
+                shape.1,
+                shape.0,
+                triplets.iter().map(|(i, j, v)| (*j, *i, *v)).collect(),
+            )?,
+        })
+    }
+
+    ///Non-zero elements of mat.
+    pub fn from_dense(mat: &Array2<f64>) -> Self {
+        let triplets: Vec<(usize, usize, f64)> = mat
+            .indexed_iter()
+            .filter(|(_, v)| **v != 0.)
+            .map(|((i, j), v)| (i, j, *v))
+            .collect();
+        Self::from_triplets((mat.nrows(), mat.ncols()), &triplets).unwrap()
+    }
+
+    ///(row, column, value) entries in column major order.
+    pub fn triplets(&self) -> Vec<(usize, usize, f64)> {
+        self.inner.entries().map(|(j, i, v)| (i, j, v)).collect()
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.inner.data.len()
+    }
+    pub fn indptr(&self) -> &[usize] {
+        &self.inner.indptr
+    }
+    pub fn indices(&self) -> &[usize] {
+        &self.inner.indices
+    }
+    pub fn data(&self) -> &[f64] {
+        &self.inner.data
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix {
+        CsrMatrix::from_triplets(self.shape(), &self.triplets()).unwrap()
+    }
+
+    ///A^T as CSC.
+    pub fn transpose(&self) -> Self {
+        Self {
+            inner: self.inner.transposed(),
+        }
+    }
+}
+
+impl LinearOperator for CscMatrix {
+    fn shape(&self) -> (usize, usize) {
+        (self.inner.inner_len, self.inner.outer_len)
+    }
+    fn mul_vec(&self, x: &Array1<f64>) -> Array1<f64> {
+        self.inner.scatter(x)
+    }
+    fn t_mul_vec(&self, y: &Array1<f64>) -> Array1<f64> {
+        self.inner.gather(y)
+    }
+    fn column_vec(&self, j: usize) -> Array1<f64> {
+        let mut column = Array::zeros(self.inner.inner_len);
+        for k in self.inner.indptr[j]..self.inner.indptr[j + 1] {
+            column[self.inner.indices[k]] += self.inner.data[k];
+        }
+        column
+    }
+    fn column_norms(&self) -> Array1<f64> {
+        Array::from_shape_fn(self.inner.outer_len, |j| {
+            self.inner.data[self.inner.indptr[j]..self.inner.indptr[j + 1]]
+                .iter()
+                .map(|v| v * v)
+                .sum::<f64>()
+                .sqrt()
+        })
+    }
+    fn diagonal(&self) -> Array1<f64> {
+        self.inner.diagonal()
+    }
+    fn to_dense(&self) -> Array2<f64> {
+        let mut mat = Array::zeros(self.shape());
+        for (j, i, v) in self.inner.entries() {
+            mat[[i, j]] += v;
+        }
+        mat
+    }
+}
+
+impl ColumnScale for CscMatrix {
+    fn div_columns(&self, divisors: &Array1<f64>) -> Self {
+        let mut mat = self.clone();
+        for j in 0..self.inner.outer_len {
+            for k in self.inner.indptr[j]..self.inner.indptr[j + 1] {
+                mat.inner.data[k] /= divisors[j];
+            }
+        }
+        mat
+    }
+}
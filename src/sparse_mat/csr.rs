@@ -0,0 +1,130 @@
+use super::{ColumnScale, Compressed, CscMatrix, LinearOperator};
+use crate::prelude::*;
+
+///Compressed sparse row matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix {
+    inner: Compressed,
+}
+
+impl CsrMatrix {
+    ///Row i's elements are data[indptr[i]..indptr[i+1]] at columns indices[indptr[i]..indptr[i+1]].
+    pub fn new(
+        shape: (usize, usize),
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<f64>,
+    ) -> Result<Self> {
+        Ok(Self {
+            inner: Compressed::new(shape.0, shape.1, indptr, indices, data)?,
+        })
+    }
+
+    ///Make from (row, column, value) entries. Duplicated entries are summed up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use sparse_modeling::sparse_mat::{CsrMatrix, LinearOperator};
+    ///
+    ///let mat = CsrMatrix::from_triplets((2, 3), &[(0, 0, 1.), (1, 2, 2.), (1, 2, 3.)]).unwrap();
+    ///assert_eq!(mat.nnz(), 2);
+    ///assert_eq!(mat.to_dense(), array![[1., 0., 0.], [0., 0., 5.]]);
+    ///assert_eq!(mat.mul_vec(&array![1., 1., 1.]), array![1., 5.]);
+    /// ```
+    pub fn from_triplets(shape: (usize, usize), triplets: &[(usize, usize, f64)]) -> Result<Self> {
+        Ok(Self {
+            inner: Compressed::from_entries(shape.0, shape.1, triplets.to_vec())?,
+        })
+    }
+
+    ///Non-zero elements of mat.
+    pub fn from_dense(mat: &Array2<f64>) -> Self {
+        let triplets: Vec<(usize, usize, f64)> = mat
+            .indexed_iter()
+            .filter(|(_, v)| **v != 0.)
+            .map(|((i, j), v)| (i, j, *v))
+            .collect();
+        Self::from_triplets((mat.nrows(), mat.ncols()), &triplets).unwrap()
+    }
+
+    ///(row, column, value) entries in row major order.
+    pub fn triplets(&self) -> Vec<(usize, usize, f64)> {
+        self.inner.entries().collect()
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.inner.data.len()
+    }
+    pub fn indptr(&self) -> &[usize] {
+        &self.inner.indptr
+    }
+    pub fn indices(&self) -> &[usize] {
+        &self.inner.indices
+    }
+    pub fn data(&self) -> &[f64] {
+        &self.inner.data
+    }
+
+    pub fn to_csc(&self) -> CscMatrix {
+        CscMatrix::from_triplets(self.shape(), &self.triplets()).unwrap()
+    }
+
+    ///A^T as CSR.
+    pub fn transpose(&self) -> Self {
+        Self {
+            inner: self.inner.transposed(),
+        }
+    }
+}
+
+impl LinearOperator for CsrMatrix {
+    fn shape(&self) -> (usize, usize) {
+        (self.inner.outer_len, self.inner.inner_len)
+    }
+    fn mul_vec(&self, x: &Array1<f64>) -> Array1<f64> {
+        self.inner.gather(x)
+    }
+    fn t_mul_vec(&self, y: &Array1<f64>) -> Array1<f64> {
+        self.inner.scatter(y)
+    }
+    fn column_vec(&self, j: usize) -> Array1<f64> {
+        let mut column = Array::zeros(self.inner.outer_len);
+        for (i, k, v) in self.inner.entries() {
+            if k == j {
+                column[i] += v;
+            }
+        }
+        column
+    }
+    fn column_norms(&self) -> Array1<f64> {
+        let mut norms: Array1<f64> = Array::zeros(self.inner.inner_len);
+        for (_, j, v) in self.inner.entries() {
+            norms[j] += v * v;
+        }
+        norms.mapv(f64::sqrt)
+    }
+    fn diagonal(&self) -> Array1<f64> {
+        self.inner.diagonal()
+    }
+    fn to_dense(&self) -> Array2<f64> {
+        let mut mat = Array::zeros(self.shape());
+        for (i, j, v) in self.inner.entries() {
+            mat[[i, j]] += v;
+        }
+        mat
+    }
+}
+
+impl ColumnScale for CsrMatrix {
+    fn div_columns(&self, divisors: &Array1<f64>) -> Self {
+        let mut mat = self.clone();
+        mat.inner
+            .data
+            .iter_mut()
+            .zip(self.inner.indices.iter())
+            .for_each(|(v, j)| *v /= divisors[*j]);
+        mat
+    }
+}
@@ -0,0 +1,212 @@
+//! # Sparse Mat
+//!
+//! 'sparse_mat' is a collection of sparse matrix types(CSR/CSC),
+//! and the linear operator abstraction which solvers consume instead of Array2<f64>.
+mod csc;
+mod csr;
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
+
+///Matrix which can be multiplied to vectors.
+pub trait LinearOperator {
+    ///(row size, column size)
+    fn shape(&self) -> (usize, usize);
+    ///A x
+    fn mul_vec(&self, x: &Array1<f64>) -> Array1<f64>;
+    ///A^T y
+    fn t_mul_vec(&self, y: &Array1<f64>) -> Array1<f64>;
+    ///j-th column
+    fn column_vec(&self, j: usize) -> Array1<f64> {
+        let mut e = Array::zeros(self.shape().1);
+        e[j] = 1.;
+        self.mul_vec(&e)
+    }
+    ///l2 norms of columns
+    fn column_norms(&self) -> Array1<f64> {
+        Array::from_shape_fn(self.shape().1, |j| self.column_vec(j).norm_l2())
+    }
+    ///diagonal elements(min(row size, column size))
+    fn diagonal(&self) -> Array1<f64> {
+        let (rows, cols) = self.shape();
+        Array::from_shape_fn(rows.min(cols), |j| self.column_vec(j)[j])
+    }
+    fn to_dense(&self) -> Array2<f64> {
+        let (rows, cols) = self.shape();
+        let mut mat = Array::zeros((rows, cols));
+        for j in 0..cols {
+            mat.column_mut(j).assign(&self.column_vec(j));
+        }
+        mat
+    }
+}
+
+///Matrix whose columns can be scaled without changing its type.
+pub trait ColumnScale: Sized {
+    ///Divide j-th column by divisors[j].
+    fn div_columns(&self, divisors: &Array1<f64>) -> Self;
+}
+
+impl LinearOperator for Array2<f64> {
+    fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+    fn mul_vec(&self, x: &Array1<f64>) -> Array1<f64> {
+        self.dot(x)
+    }
+    fn t_mul_vec(&self, y: &Array1<f64>) -> Array1<f64> {
+        self.t().dot(y)
+    }
+    fn column_vec(&self, j: usize) -> Array1<f64> {
+        self.column(j).to_owned()
+    }
+    fn column_norms(&self) -> Array1<f64> {
+        self.columns()
+            .into_iter()
+            .map(|column| column.norm_l2())
+            .collect()
+    }
+    fn diagonal(&self) -> Array1<f64> {
+        self.diag().to_owned()
+    }
+    fn to_dense(&self) -> Array2<f64> {
+        self.clone()
+    }
+}
+
+impl ColumnScale for Array2<f64> {
+    fn div_columns(&self, divisors: &Array1<f64>) -> Self {
+        self / &divisors.view().insert_axis(Axis(0))
+    }
+}
+
+//compressed storage shared by CSR(outer: rows) and CSC(outer: columns)
+#[derive(Clone, Debug, PartialEq)]
+struct Compressed {
+    outer_len: usize,
+    inner_len: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    data: Vec<f64>,
+}
+
+impl Compressed {
+    fn new(
+        outer_len: usize,
+        inner_len: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<f64>,
+    ) -> Result<Self> {
+        if indptr.len() != outer_len + 1 || indptr[0] != 0 {
+            return Err(anyhow!(format!(
+                "indptr's size is {}, it is needed to be {} and start with 0",
+                indptr.len(),
+                outer_len + 1
+            )));
+        }
+        if indptr.windows(2).any(|w| w[0] > w[1]) || indptr[outer_len] != indices.len() {
+            return Err(anyhow!(
+                "indptr is needed to be non-decreasing and end with nnz"
+            ));
+        }
+        if indices.len() != data.len() {
+            return Err(anyhow!(format!(
+                "indices' size({}) and data's size({}) are different",
+                indices.len(),
+                data.len()
+            )));
+        }
+        if indices.iter().any(|i| *i >= inner_len) {
+            return Err(anyhow!("indices are out of range"));
+        }
+        Ok(Self {
+            outer_len,
+            inner_len,
+            indptr,
+            indices,
+            data,
+        })
+    }
+
+    //(outer, inner, value) -> compressed. duplicated entries are summed up
+    fn from_entries(
+        outer_len: usize,
+        inner_len: usize,
+        mut entries: Vec<(usize, usize, f64)>,
+    ) -> Result<Self> {
+        if entries
+            .iter()
+            .any(|(o, i, _)| *o >= outer_len || *i >= inner_len)
+        {
+            return Err(anyhow!("entries are out of range"));
+        }
+        entries.sort_by_key(|(o, i, _)| (*o, *i));
+
+        let mut indptr = vec![0; outer_len + 1];
+        let mut indices: Vec<usize> = Vec::with_capacity(entries.len());
+        let mut data: Vec<f64> = Vec::with_capacity(entries.len());
+        let mut last = None;
+        for (o, i, v) in entries {
+            if last == Some((o, i)) {
+                *data.last_mut().unwrap() += v;
+                continue;
+            }
+            last = Some((o, i));
+            indptr[o + 1] += 1;
+            indices.push(i);
+            data.push(v);
+        }
+        for o in 0..outer_len {
+            indptr[o + 1] += indptr[o];
+        }
+
+        Self::new(outer_len, inner_len, indptr, indices, data)
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        (0..self.outer_len).flat_map(move |o| {
+            (self.indptr[o]..self.indptr[o + 1]).map(move |k| (o, self.indices[k], self.data[k]))
+        })
+    }
+
+    fn transposed(&self) -> Self {
+        Self::from_entries(
+            self.inner_len,
+            self.outer_len,
+            self.entries().map(|(o, i, v)| (i, o, v)).collect(),
+        )
+        .expect("transposed entries are in range")
+    }
+
+    //M[k, k] for k < min(outer_len, inner_len)
+    fn diagonal(&self) -> Array1<f64> {
+        let mut diag = Array::zeros(self.outer_len.min(self.inner_len));
+        for (o, _, v) in self.entries().filter(|(o, i, _)| o == i) {
+            diag[o] += v;
+        }
+        diag
+    }
+
+    //y[o] = sum_i M[o, i] x[i]
+    fn gather(&self, x: &Array1<f64>) -> Array1<f64> {
+        Array::from_shape_fn(self.outer_len, |o| {
+            (self.indptr[o]..self.indptr[o + 1])
+                .map(|k| self.data[k] * x[self.indices[k]])
+                .sum()
+        })
+    }
+
+    //y[i] = sum_o M[o, i] x[o]
+    fn scatter(&self, x: &Array1<f64>) -> Array1<f64> {
+        let mut y = Array::zeros(self.inner_len);
+        for (o, i, v) in self.entries() {
+            y[i] += v * x[o];
+        }
+        y
+    }
+}
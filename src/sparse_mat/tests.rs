@@ -0,0 +1,101 @@
+use super::*;
+
+fn rand_sparse_mat(rng: &mut SeededRng, shape: (usize, usize), density: f64) -> Array2<f64> {
+    Array::from_shape_fn(shape, |_| {
+        if rng.gen_bool(density) {
+            rng.gen_range(-1.0..1.0)
+        } else {
+            0.
+        }
+    })
+}
+
+#[test]
+fn sparse_mat_ops_test() {
+    let mut rng = test_rng();
+    let dense = rand_sparse_mat(&mut rng, (30, 50), 0.2);
+    let csr = CsrMatrix::from_dense(&dense);
+    let csc = CscMatrix::from_dense(&dense);
+    let x: Array1<f64> = Array::from_shape_fn(50, |_| rng.gen_range(-1.0..1.0));
+    let y: Array1<f64> = Array::from_shape_fn(30, |_| rng.gen_range(-1.0..1.0));
+
+    assert_eq!(csr.to_dense(), dense);
+    assert_eq!(csc.to_dense(), dense);
+    assert_eq!(csr.to_csc(), csc);
+    assert_eq!(csc.to_csr(), csr);
+    assert_eq!(csr.transpose().to_dense(), dense.t());
+    assert_eq!(csc.transpose().to_dense(), dense.t());
+
+    assert!((csr.mul_vec(&x) - dense.dot(&x)).norm_l2() < 1e-12);
+    assert!((csc.mul_vec(&x) - dense.dot(&x)).norm_l2() < 1e-12);
+    assert!((csr.t_mul_vec(&y) - dense.t().dot(&y)).norm_l2() < 1e-12);
+    assert!((csc.t_mul_vec(&y) - dense.t().dot(&y)).norm_l2() < 1e-12);
+    for j in 0..50 {
+        assert_eq!(csr.column_vec(j), dense.column(j));
+        assert_eq!(csc.column_vec(j), dense.column(j));
+    }
+    assert_eq!(csr.diagonal(), dense.diag());
+    assert_eq!(csc.diagonal(), dense.diag());
+
+    let normalized = normalize_columns(&dense).unwrap();
+    assert!((normalize_columns(&csr).unwrap().to_dense() - &normalized).norm_max() < 1e-12);
+    assert!((normalize_columns(&csc).unwrap().to_dense() - &normalized).norm_max() < 1e-12);
+    assert!((mutal_coherence(&csr) - mutal_coherence(&dense)).abs() < 1e-12);
+    assert!((mutal_coherence(&csc) - mutal_coherence(&dense)).abs() < 1e-12);
+    assert!((matrix_l2(&csr) - matrix_l2(&dense)).abs() < 1e-10);
+    assert!((matrix_l2(&csc) - matrix_l2(&dense)).abs() < 1e-10);
+}
+
+#[test]
+fn sparse_mat_solvers_test() {
+    let mut rng = test_rng();
+    let dense = normalize_columns(&rand_sparse_mat(&mut rng, (30, 50), 0.3)).unwrap();
+    let csr = CsrMatrix::from_dense(&dense);
+    let x = rand_pulses_signal(&mut rng, 50, 3, 1.0, 2.0).expect("can't generate a signal");
+    let y = dense.dot(&x);
+
+    let lasso_algs: Vec<Box<dyn LassoAlg>> = vec![
+        Box::new(LassoIsta::new(100, 1e-20)),
+        Box::new(LassoFista::new(100, 1e-20)),
+        Box::new(LassoIstaLipshitzSearch::new(100, 1e-20)),
+        Box::new(LassoIrls::new(100, 1e-20, 1e-4)),
+        Box::new(LassoIrlsShrink::new(100, 1e-20)),
+        Box::new(LassoSSF::new(100, 1e-20)),
+    ];
+    for lasso_alg in lasso_algs.iter() {
+        let dense_result = lasso_alg.solve(&dense, &y, 1e-2).unwrap();
+        let csr_result = lasso_alg.solve(&csr, &y, 1e-2).unwrap();
+        assert!((dense_result - csr_result).norm_l2() < 1e-6);
+    }
+
+    let omp = Omp::new(1e-8, 100);
+    let mp = Mp::new(1e-8, 1000);
+    assert!((omp.solve(&dense, &y).unwrap() - omp.solve_op(&csr, &y).unwrap()).norm_l2() < 1e-6);
+    assert!((mp.solve(&dense, &y).unwrap() - mp.solve_op(&csr, &y).unwrap()).norm_l2() < 1e-6);
+}
+
+#[test]
+fn spectral_norm_test() {
+    let mut rng = test_rng();
+    for shape in [(30, 50), (200, 500), (300, 80)] {
+        let dense = rand_sparse_mat(&mut rng, shape, 0.1);
+        let csr = CsrMatrix::from_dense(&dense);
+        let (_, s, _) = dense.svd(false, false).unwrap();
+        let exact = s.norm_max();
+
+        let lanczos = lanczos_l2(&csr, 1e-12, usize::MAX);
+        let power = power_iteration_l2(&csr, 1e-12, 100000);
+        println!(
+            "{:?}: exact {}, lanczos {}, power {}",
+            shape, exact, lanczos, power
+        );
+        assert!((lanczos - exact).abs() < 1e-10 * exact);
+        assert!((power - exact).abs() < 1e-8 * exact);
+        assert!((matrix_l2(&dense) - exact).abs() < 1e-10 * exact);
+        //a loose tolerance bounds the error by the residual
+        assert!((lanczos_l2(&csr, 1e-2, usize::MAX) - exact).abs() <= 1e-2 * exact);
+        //wide matrices are exact after row size + 1 iterations
+        let wide = dense.t().to_owned();
+        assert!((lanczos_l2(&wide, 0., usize::MAX) - exact).abs() < 1e-10 * exact);
+    }
+}
@@ -0,0 +1,151 @@
+//! # TV
+//!
+//! Total variation denoising for piecewise constant signals.
+//! (minimize (1/2)||y - x||^(2) + λ TV(x), TV(x) = Σ_i |x_(i+1) - x_i|)
+#[cfg(test)]
+mod tests;
+
+use crate::prelude::*;
+
+///Total variation Σ_i |x_(i+1) - x_i|
+pub fn tv_1d(x: &Array1<f64>) -> f64 {
+    x.windows(2).into_iter().map(|w| (w[1] - w[0]).abs()).sum()
+}
+
+///Anisotropic total variation of Array2<f64>(sum of TV of rows and columns)
+pub fn tv_2d(x: &Array2<f64>) -> f64 {
+    x.rows()
+        .into_iter()
+        .map(|row| tv_1d(&row.to_owned()))
+        .sum::<f64>()
+        + x.columns()
+            .into_iter()
+            .map(|col| tv_1d(&col.to_owned()))
+            .sum::<f64>()
+}
+
+///Exact 1-D TV denoising by Condat's direct algorithm.
+///Negative lambda is treated as 0, so y itself is returned.
+///
+/// # Examples
+///
+/// ```
+/// use sparse_modeling::tv::tv_denoise_1d;
+/// use ndarray::array;
+///
+/// let y = array![0., 0., 3., 3.];
+/// assert_eq!(tv_denoise_1d(&y, 0.5), array![0.25, 0.25, 2.75, 2.75]);
+/// ```
+pub fn tv_denoise_1d(y: &Array1<f64>, lambda: f64) -> Array1<f64> {
+    if lambda <= 0. {
+        return y.clone();
+    }
+    let width = y.len();
+    let mut x: Array1<f64> = Array::zeros(width);
+    if width == 0 {
+        return x;
+    }
+
+    //segment [k0, k] is being fitted. vmin/vmax are lower/upper bounds of its value and
+    //kminus/kplus are the last positions where they were attained.
+    let (mut k, mut k0, mut kminus, mut kplus) = (0, 0, 0, 0);
+    let (mut umin, mut umax) = (lambda, -lambda);
+    let (mut vmin, mut vmax) = (y[0] - lambda, y[0] + lambda);
+    loop {
+        while k == width - 1 {
+            if umin < 0. {
+                x.slice_mut(s![k0..=kminus]).fill(vmin);
+                k0 = kminus + 1;
+                k = k0;
+                kminus = k0;
+                vmin = y[k0];
+                umin = lambda;
+                umax = vmin + umin - vmax;
+            } else if umax > 0. {
+                x.slice_mut(s![k0..=kplus]).fill(vmax);
+                k0 = kplus + 1;
+                k = k0;
+                kplus = k0;
+                vmax = y[k0];
+                umax = -lambda;
+                umin = vmax + umax - vmin;
+            } else {
+                vmin += umin / (k - k0 + 1) as f64;
+                x.slice_mut(s![k0..=k]).fill(vmin);
+                return x;
+            }
+        }
+
+        umin += y[k + 1] - vmin;
+        if umin < -lambda {
+            //negative jump
+            x.slice_mut(s![k0..=kminus]).fill(vmin);
+            k0 = kminus + 1;
+            (k, kminus, kplus) = (k0, k0, k0);
+            vmin = y[k0];
+            vmax = vmin + 2. * lambda;
+            umin = lambda;
+            umax = -lambda;
+            continue;
+        }
+        umax += y[k + 1] - vmax;
+        if umax > lambda {
+            //positive jump
+            x.slice_mut(s![k0..=kplus]).fill(vmax);
+            k0 = kplus + 1;
+            (k, kminus, kplus) = (k0, k0, k0);
+            vmax = y[k0];
+            vmin = vmax - 2. * lambda;
+            umin = lambda;
+            umax = -lambda;
+        } else {
+            //no jump, extend the segment
+            k += 1;
+            if umin >= lambda {
+                kminus = k;
+                vmin += (umin - lambda) / (kminus - k0 + 1) as f64;
+                umin = lambda;
+            }
+            if umax <= -lambda {
+                kplus = k;
+                vmax += (umax + lambda) / (kplus - k0 + 1) as f64;
+                umax = -lambda;
+            }
+        }
+    }
+}
+
+///Anisotropic 2-D TV denoising.
+///The proximal operator of row-wise TV + column-wise TV is calculated by
+///proximal Dykstra's algorithm with exact 1-D TV denoising of rows and columns.
+pub fn tv_denoise_2d(y: &Array2<f64>, lambda: f64, iter_num: usize, threshold: f64) -> Array2<f64> {
+    let mut x = y.clone();
+    let mut p: Array2<f64> = Array::zeros(y.raw_dim());
+    let mut q: Array2<f64> = Array::zeros(y.raw_dim());
+
+    for _ in 0..iter_num {
+        let prev_x = x.clone();
+
+        //TV denoising of rows
+        let mut z = &x + &p;
+        for mut row in z.rows_mut() {
+            let denoised = tv_denoise_1d(&row.to_owned(), lambda);
+            row.assign(&denoised);
+        }
+        p = &x + &p - &z;
+
+        //TV denoising of columns
+        x = &z + &q;
+        for mut col in x.columns_mut() {
+            let denoised = tv_denoise_1d(&col.to_owned(), lambda);
+            col.assign(&denoised);
+        }
+        q = &z + &q - &x;
+
+        if (&x - &prev_x).norm_l2() < threshold {
+            break;
+        }
+    }
+
+    x
+}
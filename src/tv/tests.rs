@@ -0,0 +1,64 @@
+use super::*;
+
+//objective (1/2)||y - x||^2 + λ TV(x)
+fn tv_objective(y: &Array1<f64>, x: &Array1<f64>, lambda: f64) -> f64 {
+    0.5 * (y - x).norm_l2().powi(2) + lambda * tv_1d(x)
+}
+
+#[test]
+fn tv_denoise_1d_test() {
+    let mut rng = test_rng();
+    let lambda = 0.5;
+    for size in 1..40 {
+        let y: Array1<f64> = Array::from_shape_fn(size, |_| rng.gen_range(-2.0..2.0));
+        let x = tv_denoise_1d(&y, lambda);
+
+        //x is optimal iff it is not improved by moving any segment and any element
+        let objective = tv_objective(&y, &x, lambda);
+        for i in 0..size {
+            for delta in [1e-4, -1e-4] {
+                let mut moved = x.clone();
+                moved[i] += delta;
+                assert!(tv_objective(&y, &moved, lambda) >= objective - 1e-12);
+                let mut shifted = x.clone();
+                shifted.mapv_inplace(|v| {
+                    if (v - x[i]).abs() < 1e-12 {
+                        v + delta
+                    } else {
+                        v
+                    }
+                });
+                assert!(tv_objective(&y, &shifted, lambda) >= objective - 1e-12);
+            }
+        }
+        //mean is kept
+        assert!((x.sum() - y.sum()).abs() < 1e-9);
+    }
+
+    //lambda <= 0 keeps y
+    let y = array![1., -2., 3.];
+    assert_eq!(tv_denoise_1d(&y, 0.), y);
+    assert_eq!(tv_denoise_1d(&y, -1.), y);
+    assert_eq!(tv_denoise_1d(&array![1.], -1.), array![1.]);
+}
+
+#[test]
+fn tv_denoise_2d_test() {
+    let mut rng = test_rng();
+    let image: Array2<f64> = Array::from_shape_fn((32, 32), |(i, j)| {
+        if (8..24).contains(&i) && (8..24).contains(&j) {
+            1.
+        } else {
+            0.
+        }
+    });
+    let noisy = &image + &rand_gaussian_mat(&mut rng, (32, 32), 0.2);
+    let denoised = tv_denoise_2d(&noisy, 0.3, 200, 1e-8);
+    println!(
+        "noisy error: {}, denoised error: {}",
+        (&noisy - &image).norm_l2(),
+        (&denoised - &image).norm_l2()
+    );
+    assert!((&denoised - &image).norm_l2() < 0.5 * (&noisy - &image).norm_l2());
+    assert!(tv_2d(&denoised) < tv_2d(&noisy));
+}